@@ -35,14 +35,6 @@ fn main() {
 
     println!("cargo:rustc-cfg=curve25519_dalek_bits=\"{curve25519_dalek_bits}\"");
 
-    if rustc_version::version_meta()
-        .expect("failed to detect rustc version")
-        .channel
-        == rustc_version::Channel::Nightly
-    {
-        println!("cargo:rustc-cfg=nightly");
-    }
-
     let rustc_version = rustc_version::version().expect("failed to detect rustc version");
     if rustc_version.major == 1 && rustc_version.minor <= 64 {
         // Old versions of Rust complain when you have an `unsafe fn` and you use `unsafe {}` inside,
@@ -50,6 +42,12 @@ fn main() {
         println!("cargo:rustc-cfg=allow_unused_unsafe");
     }
 
+    if rustc_version.major > 1 || rustc_version.minor >= 89 {
+        // The AVX512 intrinsics and target features used by the IFMA backend
+        // are stable since Rust 1.89.
+        println!("cargo:rustc-cfg=curve25519_dalek_avx512");
+    }
+
     // Backend overrides / defaults
     let curve25519_dalek_backend =
         match std::env::var("CARGO_CFG_CURVE25519_DALEK_BACKEND").as_deref() {
@@ -0,0 +1,200 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Differential checking of the runtime-selected backend against the
+//! serial backend.
+//!
+//! Each function here computes its result twice: once with the backend
+//! chosen by runtime CPU feature detection, exactly as the corresponding
+//! public API would, and once with the portable serial backend.  A
+//! disagreement indicates a bug in one of the backends or faulty
+//! hardware, and is returned as a [`Mismatch`] instead of a result.
+//!
+//! When no SIMD backend is compiled in or supported by the CPU, the
+//! selected backend *is* the serial backend, so both computations take
+//! the same code path and the checks can never fail.  Use
+//! [`selected_backend`] to find out whether a check is meaningful.
+//!
+//! The checks cost more than twice as much as the plain operations.  They
+//! are meant for test suites and for bringing up new targets, not for
+//! production use.
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "hazmat", doc = "```")]
+#![cfg_attr(not(feature = "hazmat"), doc = "```ignore")]
+//! use curve25519_dalek::differential;
+//! use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+//! use curve25519_dalek::scalar::Scalar;
+//!
+//! let x = Scalar::from(1234u64);
+//! match differential::variable_base_mul(&ED25519_BASEPOINT_POINT, &x) {
+//!     Ok(point) => assert_eq!(point, ED25519_BASEPOINT_POINT * x),
+//!     Err(mismatch) => panic!("{}", mismatch),
+//! }
+//! ```
+
+use core::fmt;
+
+pub use super::Backend;
+
+use super::{get_selected_backend, serial};
+use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
+use crate::scalar::Scalar;
+
+/// Return the backend selected for the current CPU.
+pub fn selected_backend() -> Backend {
+    get_selected_backend().into()
+}
+
+/// A disagreement between the selected backend and the serial backend.
+///
+/// The two results are stored compressed, to keep the error small.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Mismatch {
+    /// The name of the operation that disagreed.
+    pub operation: &'static str,
+    /// The backend under test.
+    pub backend: Backend,
+    /// The result computed by the backend under test.
+    pub result: CompressedEdwardsY,
+    /// The result computed by the serial backend.
+    pub expected: CompressedEdwardsY,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {:?} backend disagrees with the serial backend",
+            self.operation, self.backend
+        )
+    }
+}
+
+fn compare(
+    operation: &'static str,
+    result: EdwardsPoint,
+    expected: EdwardsPoint,
+) -> Result<EdwardsPoint, Mismatch> {
+    if result == expected {
+        Ok(result)
+    } else {
+        Err(Mismatch {
+            operation,
+            backend: selected_backend(),
+            result: result.compress(),
+            expected: expected.compress(),
+        })
+    }
+}
+
+/// Check constant-time variable-base scalar multiplication, as used by
+/// `EdwardsPoint * Scalar`.
+pub fn variable_base_mul(point: &EdwardsPoint, scalar: &Scalar) -> Result<EdwardsPoint, Mismatch> {
+    compare(
+        "variable_base_mul",
+        super::variable_base_mul(point, scalar),
+        serial::scalar_mul::variable_base::mul(point, scalar),
+    )
+}
+
+/// Check the variable-time computation of \\(aA + bB\\), where \\(B\\) is
+/// the Ed25519 basepoint, as used by
+/// `EdwardsPoint::vartime_double_scalar_mul_basepoint`.
+#[allow(non_snake_case)]
+pub fn vartime_double_base_mul(
+    a: &Scalar,
+    A: &EdwardsPoint,
+    b: &Scalar,
+) -> Result<EdwardsPoint, Mismatch> {
+    compare(
+        "vartime_double_base_mul",
+        super::vartime_double_base_mul(a, A, b),
+        serial::scalar_mul::vartime_double_base::mul(a, A, b),
+    )
+}
+
+/// Check constant-time multiscalar multiplication, as used by
+/// `EdwardsPoint::multiscalar_mul`.
+///
+/// # Panics
+///
+/// Panics if `scalars` and `points` have different lengths.
+#[cfg(feature = "alloc")]
+pub fn multiscalar_mul(
+    scalars: &[Scalar],
+    points: &[EdwardsPoint],
+) -> Result<EdwardsPoint, Mismatch> {
+    use crate::traits::MultiscalarMul;
+
+    assert_eq!(scalars.len(), points.len());
+    compare(
+        "multiscalar_mul",
+        super::straus_multiscalar_mul(scalars, points),
+        serial::scalar_mul::straus::Straus::multiscalar_mul(scalars, points),
+    )
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::constants;
+    use crate::traits::Identity;
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+    use std::string::ToString;
+
+    #[test]
+    fn selected_backend_agrees_with_serial() {
+        let mut rng = rand::thread_rng();
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+        let A = constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng);
+
+        assert_eq!(variable_base_mul(&A, &a), Ok(A * a));
+        assert_eq!(
+            vartime_double_base_mul(&a, &A, &b),
+            Ok(EdwardsPoint::vartime_double_scalar_mul_basepoint(
+                &a, &A, &b
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn multiscalar_mul_agrees_with_serial() {
+        let mut rng = rand::thread_rng();
+        let scalars: Vec<Scalar> = (0..16).map(|_| Scalar::random(&mut rng)).collect();
+        let points: Vec<EdwardsPoint> = (0..16)
+            .map(|_| constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng))
+            .collect();
+
+        let expected = scalars
+            .iter()
+            .zip(&points)
+            .fold(EdwardsPoint::default(), |acc, (s, P)| acc + P * s);
+        assert_eq!(multiscalar_mul(&scalars, &points), Ok(expected));
+    }
+
+    #[test]
+    fn mismatch_display() {
+        let mismatch = Mismatch {
+            operation: "variable_base_mul",
+            backend: Backend::Avx2,
+            result: CompressedEdwardsY::identity(),
+            expected: constants::ED25519_BASEPOINT_COMPRESSED,
+        };
+        assert_eq!(
+            mismatch.to_string(),
+            "variable_base_mul: Avx2 backend disagrees with the serial backend"
+        );
+    }
+}
@@ -34,7 +34,16 @@
 //! The [`vector`] backend is selected by the `simd_backend` cargo
 //! feature; it uses the [`serial`] backend for non-vectorized operations.
 
+use subtle::Choice;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::edwards::CompressedEdwardsY;
+use crate::field::FieldElement;
 use crate::EdwardsPoint;
+use crate::MontgomeryPoint;
 use crate::Scalar;
 
 pub mod serial;
@@ -42,18 +51,79 @@ pub mod serial;
 #[cfg(curve25519_dalek_backend = "simd")]
 pub mod vector;
 
+#[cfg(feature = "hazmat")]
+pub mod differential;
+
 #[derive(Copy, Clone)]
-enum BackendKind {
+pub(crate) enum BackendKind {
     #[cfg(curve25519_dalek_backend = "simd")]
     Avx2,
-    #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+    #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
     Avx512,
     Serial,
 }
 
+/// A backend which can be selected at runtime.
+#[cfg(feature = "hazmat")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Backend {
+    /// The portable serial backend.
+    Serial,
+    /// The AVX2 vector backend.
+    Avx2,
+    /// The AVX-512 IFMA vector backend.
+    Avx512Ifma,
+}
+
+#[cfg(feature = "hazmat")]
+impl From<BackendKind> for Backend {
+    fn from(kind: BackendKind) -> Backend {
+        match kind {
+            #[cfg(curve25519_dalek_backend = "simd")]
+            BackendKind::Avx2 => Backend::Avx2,
+            #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
+            BackendKind::Avx512 => Backend::Avx512Ifma,
+            BackendKind::Serial => Backend::Serial,
+        }
+    }
+}
+
+#[cfg(feature = "hazmat")]
+impl Backend {
+    /// Return whether this backend can be used on the current CPU, with
+    /// the backends compiled into this build.
+    pub fn is_available(self) -> bool {
+        BackendKind::for_backend(self).is_some()
+    }
+}
+
+#[cfg(feature = "hazmat")]
+impl BackendKind {
+    /// Return the `BackendKind` for `backend`, or `None` if it is not
+    /// available on the current CPU.
+    fn for_backend(backend: Backend) -> Option<BackendKind> {
+        match backend {
+            Backend::Serial => Some(BackendKind::Serial),
+            #[cfg(curve25519_dalek_backend = "simd")]
+            Backend::Avx2 => {
+                cpufeatures::new!(cpuid_avx2, "avx2");
+                cpuid_avx2::get().then(|| BackendKind::Avx2)
+            }
+            #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
+            Backend::Avx512Ifma => {
+                cpufeatures::new!(cpuid_avx512, "avx512ifma", "avx512vl");
+                cpuid_avx512::get().then(|| BackendKind::Avx512)
+            }
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+}
+
 #[inline]
 fn get_selected_backend() -> BackendKind {
-    #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+    #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
     {
         cpufeatures::new!(cpuid_avx512, "avx512ifma", "avx512vl");
         let token_avx512: cpuid_avx512::InitToken = cpuid_avx512::init();
@@ -88,7 +158,7 @@ where
         #[cfg(curve25519_dalek_backend = "simd")]
         BackendKind::Avx2 =>
             vector::scalar_mul::pippenger::spec_avx2::Pippenger::optional_multiscalar_mul::<I, J>(scalars, points),
-        #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+        #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
         BackendKind::Avx512 =>
             vector::scalar_mul::pippenger::spec_avx512ifma_avx512vl::Pippenger::optional_multiscalar_mul::<I, J>(scalars, points),
         BackendKind::Serial =>
@@ -100,7 +170,7 @@ where
 pub(crate) enum VartimePrecomputedStraus {
     #[cfg(curve25519_dalek_backend = "simd")]
     Avx2(vector::scalar_mul::precomputed_straus::spec_avx2::VartimePrecomputedStraus),
-    #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+    #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
     Avx512ifma(
         vector::scalar_mul::precomputed_straus::spec_avx512ifma_avx512vl::VartimePrecomputedStraus,
     ),
@@ -120,7 +190,7 @@ impl VartimePrecomputedStraus {
             #[cfg(curve25519_dalek_backend = "simd")]
             BackendKind::Avx2 =>
                 VartimePrecomputedStraus::Avx2(vector::scalar_mul::precomputed_straus::spec_avx2::VartimePrecomputedStraus::new(static_points)),
-            #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+            #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
             BackendKind::Avx512 =>
                 VartimePrecomputedStraus::Avx512ifma(vector::scalar_mul::precomputed_straus::spec_avx512ifma_avx512vl::VartimePrecomputedStraus::new(static_points)),
             BackendKind::Serial =>
@@ -150,7 +220,7 @@ impl VartimePrecomputedStraus {
                 dynamic_scalars,
                 dynamic_points,
             ),
-            #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+            #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
             VartimePrecomputedStraus::Avx512ifma(inner) => inner.optional_mixed_multiscalar_mul(
                 static_scalars,
                 dynamic_scalars,
@@ -168,6 +238,19 @@ impl VartimePrecomputedStraus {
 #[allow(missing_docs)]
 #[cfg(feature = "alloc")]
 pub fn straus_multiscalar_mul<I, J>(scalars: I, points: J) -> EdwardsPoint
+where
+    I: IntoIterator,
+    I::Item: core::borrow::Borrow<Scalar>,
+    J: IntoIterator,
+    J::Item: core::borrow::Borrow<EdwardsPoint>,
+{
+    straus_multiscalar_mul_on(get_selected_backend(), scalars, points)
+}
+
+/// Perform constant-time multiscalar multiplication with the given
+/// backend.
+#[cfg(feature = "alloc")]
+fn straus_multiscalar_mul_on<I, J>(kind: BackendKind, scalars: I, points: J) -> EdwardsPoint
 where
     I: IntoIterator,
     I::Item: core::borrow::Borrow<Scalar>,
@@ -176,12 +259,12 @@ where
 {
     use crate::traits::MultiscalarMul;
 
-    match get_selected_backend() {
+    match kind {
         #[cfg(curve25519_dalek_backend = "simd")]
         BackendKind::Avx2 => {
             vector::scalar_mul::straus::spec_avx2::Straus::multiscalar_mul::<I, J>(scalars, points)
         }
-        #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+        #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
         BackendKind::Avx512 => {
             vector::scalar_mul::straus::spec_avx512ifma_avx512vl::Straus::multiscalar_mul::<I, J>(
                 scalars, points,
@@ -210,7 +293,7 @@ where
                 scalars, points,
             )
         }
-        #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+        #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
         BackendKind::Avx512 => {
             vector::scalar_mul::straus::spec_avx512ifma_avx512vl::Straus::optional_multiscalar_mul::<
                 I,
@@ -225,10 +308,16 @@ where
 
 /// Perform constant-time, variable-base scalar multiplication.
 pub fn variable_base_mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
-    match get_selected_backend() {
+    variable_base_mul_on(get_selected_backend(), point, scalar)
+}
+
+/// Perform constant-time, variable-base scalar multiplication with the
+/// given backend.
+fn variable_base_mul_on(kind: BackendKind, point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint {
+    match kind {
         #[cfg(curve25519_dalek_backend = "simd")]
         BackendKind::Avx2 => vector::scalar_mul::variable_base::spec_avx2::mul(point, scalar),
-        #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+        #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
         BackendKind::Avx512 => {
             vector::scalar_mul::variable_base::spec_avx512ifma_avx512vl::mul(point, scalar)
         }
@@ -236,13 +325,118 @@ pub fn variable_base_mul(point: &EdwardsPoint, scalar: &Scalar) -> EdwardsPoint
     }
 }
 
+/// Perform four independent constant-time Montgomery ladders.
+pub fn montgomery_mul_x4(
+    points: &[MontgomeryPoint; 4],
+    scalars: &[Scalar; 4],
+) -> [MontgomeryPoint; 4] {
+    match get_selected_backend() {
+        #[cfg(curve25519_dalek_backend = "simd")]
+        BackendKind::Avx2 => vector::avx2::montgomery::mul_x4(points, scalars),
+        #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
+        BackendKind::Avx512 => vector::ifma::montgomery::mul_x4(points, scalars),
+        BackendKind::Serial => [
+            points[0] * scalars[0],
+            points[1] * scalars[1],
+            points[2] * scalars[2],
+            points[3] * scalars[3],
+        ],
+    }
+}
+
+/// Perform constant-time, variable-base scalar multiplication with
+/// `backend`, if it is available.
+#[cfg(feature = "hazmat")]
+pub fn variable_base_mul_with(
+    backend: Backend,
+    point: &EdwardsPoint,
+    scalar: &Scalar,
+) -> Option<EdwardsPoint> {
+    BackendKind::for_backend(backend).map(|kind| variable_base_mul_on(kind, point, scalar))
+}
+
+/// Compute \\(aA + bB\\) in variable time with `backend`, if it is
+/// available.
+#[cfg(feature = "hazmat")]
+#[allow(non_snake_case)]
+pub fn vartime_double_base_mul_with(
+    backend: Backend,
+    a: &Scalar,
+    A: &EdwardsPoint,
+    b: &Scalar,
+) -> Option<EdwardsPoint> {
+    BackendKind::for_backend(backend).map(|kind| vartime_double_base_mul_on(kind, a, A, b))
+}
+
+/// Perform constant-time multiscalar multiplication with `backend`, if it
+/// is available.
+#[cfg(all(feature = "hazmat", feature = "alloc"))]
+pub fn straus_multiscalar_mul_with(
+    backend: Backend,
+    scalars: &[Scalar],
+    points: &[EdwardsPoint],
+) -> Option<EdwardsPoint> {
+    BackendKind::for_backend(backend).map(|kind| straus_multiscalar_mul_on(kind, scalars, points))
+}
+
+/// Compress a batch of Edwards points, sharing one field inversion.
+#[cfg(feature = "alloc")]
+#[allow(non_snake_case)]
+pub(crate) fn compress_batch(points: &[&EdwardsPoint]) -> Vec<CompressedEdwardsY> {
+    match get_selected_backend() {
+        #[cfg(curve25519_dalek_backend = "simd")]
+        BackendKind::Avx2 => vector::avx2::compress::compress_batch(points),
+        #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
+        BackendKind::Avx512 => vector::ifma::compress::compress_batch(points),
+        BackendKind::Serial => {
+            let mut z_inv: Vec<FieldElement> = points.iter().map(|P| P.Z).collect();
+            FieldElement::batch_invert(&mut z_inv);
+            points
+                .iter()
+                .zip(z_inv.iter())
+                .map(|(P, z_inv)| CompressedEdwardsY::from_affine(&(&P.X * z_inv), &(&P.Y * z_inv)))
+                .collect()
+        }
+    }
+}
+
+/// Compute `FieldElement::sqrt_ratio_i` for four pairs of inputs.
+pub(crate) fn sqrt_ratio_i_x4(
+    u: &[FieldElement; 4],
+    v: &[FieldElement; 4],
+) -> [(Choice, FieldElement); 4] {
+    match get_selected_backend() {
+        #[cfg(curve25519_dalek_backend = "simd")]
+        BackendKind::Avx2 => vector::avx2::field::FieldElement2625x4::sqrt_ratio_i(u, v),
+        #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
+        BackendKind::Avx512 => vector::ifma::field::F51x4Reduced::sqrt_ratio_i(u, v),
+        BackendKind::Serial => [
+            FieldElement::sqrt_ratio_i(&u[0], &v[0]),
+            FieldElement::sqrt_ratio_i(&u[1], &v[1]),
+            FieldElement::sqrt_ratio_i(&u[2], &v[2]),
+            FieldElement::sqrt_ratio_i(&u[3], &v[3]),
+        ],
+    }
+}
+
 /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the Ed25519 basepoint.
 #[allow(non_snake_case)]
 pub fn vartime_double_base_mul(a: &Scalar, A: &EdwardsPoint, b: &Scalar) -> EdwardsPoint {
-    match get_selected_backend() {
+    vartime_double_base_mul_on(get_selected_backend(), a, A, b)
+}
+
+/// Compute \\(aA + bB\\) in variable time with the given backend.
+#[allow(non_snake_case)]
+fn vartime_double_base_mul_on(
+    kind: BackendKind,
+    a: &Scalar,
+    A: &EdwardsPoint,
+    b: &Scalar,
+) -> EdwardsPoint {
+    match kind {
         #[cfg(curve25519_dalek_backend = "simd")]
         BackendKind::Avx2 => vector::scalar_mul::vartime_double_base::spec_avx2::mul(a, A, b),
-        #[cfg(all(curve25519_dalek_backend = "simd", nightly))]
+        #[cfg(all(curve25519_dalek_backend = "simd", curve25519_dalek_avx512))]
         BackendKind::Avx512 => {
             vector::scalar_mul::vartime_double_base::spec_avx512ifma_avx512vl::mul(a, A, b)
         }
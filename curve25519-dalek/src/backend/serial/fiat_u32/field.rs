@@ -131,6 +131,18 @@ impl<'a, 'b> Mul<&'b FieldElement2625> for &'a FieldElement2625 {
     }
 }
 
+impl FieldElement2625 {
+    /// Compute `self * b + c`.
+    ///
+    /// fiat-crypto has no fused multiply-add, and this backend only uses
+    /// its verified routines, so this is a multiplication followed by an
+    /// addition and a second carry chain.
+    #[cfg(feature = "hazmat")]
+    pub fn mul_add(&self, b: &FieldElement2625, c: &FieldElement2625) -> FieldElement2625 {
+        &(self * b) + c
+    }
+}
+
 impl<'a> Neg for &'a FieldElement2625 {
     type Output = FieldElement2625;
     fn neg(self) -> FieldElement2625 {
@@ -193,6 +205,23 @@ impl FieldElement2625 {
         FieldElement2625(fiat_25519_tight_field_element(limbs))
     }
 
+    /// Construct a `FieldElement2625` from its radix-\\( 2\^{25.5} \\)
+    /// limbs, so that the value is
+    /// \\( \sum\_{i=0}\^{9} \text{limbs}[i] \cdot 2\^{\lceil 25.5 i \rceil} \\).
+    ///
+    /// Even-indexed limbs must be less than \\( 2\^{26} \\) and
+    /// odd-indexed limbs less than \\( 2\^{25} \\), as required by
+    /// fiat-crypto.  These bounds are only checked in debug builds.
+    #[cfg(feature = "hazmat")]
+    pub const fn from_raw_limbs(limbs: [u32; 10]) -> FieldElement2625 {
+        let mut i = 0;
+        while i < 10 {
+            debug_assert!(limbs[i] < (1 << (26 - (i % 2))), "limb out of range");
+            i += 1;
+        }
+        FieldElement2625::from_limbs(limbs)
+    }
+
     /// The scalar \\( 0 \\).
     pub const ZERO: FieldElement2625 = FieldElement2625::from_limbs([0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
     /// The scalar \\( 1 \\).
@@ -210,7 +239,7 @@ impl FieldElement2625 {
     }
 
     /// Given `k > 0`, return `self^(2^k)`.
-    pub fn pow2k(&self, k: u32) -> FieldElement2625 {
+    pub(crate) fn pow2k(&self, k: u32) -> FieldElement2625 {
         debug_assert!(k > 0);
         let mut z = self.square();
         for _ in 1..k {
@@ -120,6 +120,18 @@ impl<'a, 'b> Mul<&'b FieldElement51> for &'a FieldElement51 {
     }
 }
 
+impl FieldElement51 {
+    /// Compute `self * b + c`.
+    ///
+    /// fiat-crypto has no fused multiply-add, and this backend only uses
+    /// its verified routines, so this is a multiplication followed by an
+    /// addition and a second carry chain.
+    #[cfg(feature = "hazmat")]
+    pub fn mul_add(&self, b: &FieldElement51, c: &FieldElement51) -> FieldElement51 {
+        &(self * b) + c
+    }
+}
+
 impl<'a> Neg for &'a FieldElement51 {
     type Output = FieldElement51;
     fn neg(self) -> FieldElement51 {
@@ -172,6 +184,22 @@ impl FieldElement51 {
         FieldElement51(fiat_25519_tight_field_element(limbs))
     }
 
+    /// Construct a `FieldElement51` from its radix-\\( 2\^{51} \\) limbs,
+    /// so that the value is
+    /// \\( \sum\_{i=0}\^{4} \text{limbs}[i] \cdot 2\^{51 i} \\).
+    ///
+    /// Each limb must be less than \\( 2\^{51} \\), as required by
+    /// fiat-crypto.  This bound is only checked in debug builds.
+    #[cfg(feature = "hazmat")]
+    pub const fn from_raw_limbs(limbs: [u64; 5]) -> FieldElement51 {
+        let mut i = 0;
+        while i < 5 {
+            debug_assert!(limbs[i] < (1 << 51), "limb out of range");
+            i += 1;
+        }
+        FieldElement51::from_limbs(limbs)
+    }
+
     /// The scalar \\( 0 \\).
     pub const ZERO: FieldElement51 = FieldElement51::from_limbs([0, 0, 0, 0, 0]);
     /// The scalar \\( 1 \\).
@@ -225,7 +253,8 @@ impl FieldElement51 {
     }
 
     /// Given `k > 0`, return `self^(2^k)`.
-    pub fn pow2k(&self, mut k: u32) -> FieldElement51 {
+    pub(crate) fn pow2k(&self, mut k: u32) -> FieldElement51 {
+        debug_assert!(k > 0);
         let mut output = *self;
         loop {
             let mut input = fiat_25519_loose_field_element([0; 5]);
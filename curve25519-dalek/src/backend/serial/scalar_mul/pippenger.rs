@@ -16,9 +16,10 @@ use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::cmp::Ordering;
 
+use crate::backend::serial::curve_models::ProjectiveNielsPoint;
 use crate::edwards::EdwardsPoint;
 use crate::scalar::Scalar;
-use crate::traits::VartimeMultiscalarMul;
+use crate::traits::{Identity, VartimeMultiscalarMul};
 
 /// Implements a version of Pippenger's algorithm.
 ///
@@ -70,90 +71,153 @@ impl VartimeMultiscalarMul for Pippenger {
         I::Item: Borrow<Scalar>,
         J: IntoIterator<Item = Option<EdwardsPoint>>,
     {
-        use crate::traits::Identity;
-
         let mut scalars = scalars.into_iter();
         let size = scalars.by_ref().size_hint().0;
+        let w = Pippenger::window_width(size);
+        let digits_count: usize = Scalar::to_radix_2w_size_hint(w);
+        let buckets_count: usize = (1 << w) / 2; // digits are signed+centered hence 2^w/2, excluding 0-th bucket
+
+        let scalars_points = Pippenger::recode(scalars, points, w)?;
+
+        // Prepare 2^w/2 buckets.
+        // buckets[i] corresponds to a multiplication factor (i+1).
+        let mut buckets: Vec<_> = (0..buckets_count)
+            .map(|_| EdwardsPoint::identity())
+            .collect();
+
+        let mut columns = (0..digits_count)
+            .rev()
+            .map(|digit_index| Pippenger::column(&scalars_points, digit_index, &mut buckets));
+
+        // Take the high column as an initial value to avoid wasting time doubling the identity element in `fold()`.
+        let hi_column = columns.next().expect("should have more than zero digits");
 
-        // Digit width in bits. As digit width grows,
-        // number of point additions goes down, but amount of
-        // buckets and bucket additions grows exponentially.
-        let w = if size < 500 {
+        Some(columns.fold(hi_column, |total, p| total.mul_by_pow_2(w as u32) + p))
+    }
+}
+
+/// A point and the radix-\\(2^w\\) digits of its scalar.
+type RecodedPair = ([i8; 64], ProjectiveNielsPoint);
+
+impl Pippenger {
+    /// Choose the digit width in bits for `size` point-scalar pairs.
+    fn window_width(size: usize) -> usize {
+        // As digit width grows, number of point additions goes down, but
+        // amount of buckets and bucket additions grows exponentially.
+        if size < 500 {
             6
         } else if size < 800 {
             7
         } else {
             8
-        };
-
-        let max_digit: usize = 1 << w;
-        let digits_count: usize = Scalar::to_radix_2w_size_hint(w);
-        let buckets_count: usize = max_digit / 2; // digits are signed+centered hence 2^w/2, excluding 0-th bucket
+        }
+    }
 
-        // Collect optimized scalars and points in buffers for repeated access
-        // (scanning the whole set per digit position).
+    /// Collect optimized scalars and points in buffers for repeated access
+    /// (scanning the whole set per digit position).
+    fn recode<I, J>(scalars: I, points: J, w: usize) -> Option<Vec<RecodedPair>>
+    where
+        I: Iterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
         let scalars = scalars.map(|s| s.borrow().as_radix_2w(w));
 
         let points = points
             .into_iter()
             .map(|p| p.map(|P| P.as_projective_niels()));
 
-        let scalars_points = scalars
+        scalars
             .zip(points)
             .map(|(s, maybe_p)| maybe_p.map(|p| (s, p)))
-            .collect::<Option<Vec<_>>>()?;
+            .collect::<Option<Vec<_>>>()
+    }
 
-        // Prepare 2^w/2 buckets.
-        // buckets[i] corresponds to a multiplication factor (i+1).
-        let mut buckets: Vec<_> = (0..buckets_count)
-            .map(|_| EdwardsPoint::identity())
-            .collect();
+    /// Compute the sum of one column of digits, using `buckets` as
+    /// scratch space.
+    fn column(
+        scalars_points: &[RecodedPair],
+        digit_index: usize,
+        buckets: &mut [EdwardsPoint],
+    ) -> EdwardsPoint {
+        let buckets_count = buckets.len();
 
-        let mut columns = (0..digits_count).rev().map(|digit_index| {
-            // Clear the buckets when processing another digit.
-            for bucket in &mut buckets {
-                *bucket = EdwardsPoint::identity();
-            }
+        // Clear the buckets when processing another digit.
+        for bucket in buckets.iter_mut() {
+            *bucket = EdwardsPoint::identity();
+        }
 
-            // Iterate over pairs of (point, scalar)
-            // and add/sub the point to the corresponding bucket.
-            // Note: if we add support for precomputed lookup tables,
-            // we'll be adding/subtracting point premultiplied by `digits[i]` to buckets[0].
-            for (digits, pt) in scalars_points.iter() {
-                // Widen digit so that we don't run into edge cases when w=8.
-                let digit = digits[digit_index] as i16;
-                match digit.cmp(&0) {
-                    Ordering::Greater => {
-                        let b = (digit - 1) as usize;
-                        buckets[b] = (&buckets[b] + pt).as_extended();
-                    }
-                    Ordering::Less => {
-                        let b = (-digit - 1) as usize;
-                        buckets[b] = (&buckets[b] - pt).as_extended();
-                    }
-                    Ordering::Equal => {}
+        // Iterate over pairs of (point, scalar)
+        // and add/sub the point to the corresponding bucket.
+        // Note: if we add support for precomputed lookup tables,
+        // we'll be adding/subtracting point premultiplied by `digits[i]` to buckets[0].
+        for (digits, pt) in scalars_points.iter() {
+            // Widen digit so that we don't run into edge cases when w=8.
+            let digit = digits[digit_index] as i16;
+            match digit.cmp(&0) {
+                Ordering::Greater => {
+                    let b = (digit - 1) as usize;
+                    buckets[b] = (&buckets[b] + pt).as_extended();
+                }
+                Ordering::Less => {
+                    let b = (-digit - 1) as usize;
+                    buckets[b] = (&buckets[b] - pt).as_extended();
                 }
+                Ordering::Equal => {}
             }
+        }
 
-            // Add the buckets applying the multiplication factor to each bucket.
-            // The most efficient way to do that is to have a single sum with two running sums:
-            // an intermediate sum from last bucket to the first, and a sum of intermediate sums.
-            //
-            // For example, to add buckets 1*A, 2*B, 3*C we need to add these points:
-            //   C
-            //   C B
-            //   C B A   Sum = C + (C+B) + (C+B+A)
-            let mut buckets_intermediate_sum = buckets[buckets_count - 1];
-            let mut buckets_sum = buckets[buckets_count - 1];
-            for i in (0..(buckets_count - 1)).rev() {
-                buckets_intermediate_sum += buckets[i];
-                buckets_sum += buckets_intermediate_sum;
-            }
+        // Add the buckets applying the multiplication factor to each bucket.
+        // The most efficient way to do that is to have a single sum with two running sums:
+        // an intermediate sum from last bucket to the first, and a sum of intermediate sums.
+        //
+        // For example, to add buckets 1*A, 2*B, 3*C we need to add these points:
+        //   C
+        //   C B
+        //   C B A   Sum = C + (C+B) + (C+B+A)
+        let mut buckets_intermediate_sum = buckets[buckets_count - 1];
+        let mut buckets_sum = buckets[buckets_count - 1];
+        for i in (0..(buckets_count - 1)).rev() {
+            buckets_intermediate_sum += buckets[i];
+            buckets_sum += buckets_intermediate_sum;
+        }
 
-            buckets_sum
-        });
+        buckets_sum
+    }
 
-        // Take the high column as an initial value to avoid wasting time doubling the identity element in `fold()`.
+    /// Compute the multiscalar multiplication like
+    /// `optional_multiscalar_mul`, but with the columns of digits
+    /// accumulated in parallel on the `rayon` thread pool.
+    ///
+    /// Each column is an independent job with its own buckets, and the
+    /// columns are combined in order afterwards, so the result does not
+    /// depend on how the jobs are scheduled.
+    #[cfg(feature = "rayon")]
+    pub fn par_optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        use rayon::prelude::*;
+
+        let mut scalars = scalars.into_iter();
+        let size = scalars.by_ref().size_hint().0;
+        let w = Pippenger::window_width(size);
+        let digits_count: usize = Scalar::to_radix_2w_size_hint(w);
+        let buckets_count: usize = (1 << w) / 2;
+
+        let scalars_points = Pippenger::recode(scalars, points, w)?;
+
+        let columns: Vec<EdwardsPoint> = (0..digits_count)
+            .into_par_iter()
+            .map_init(
+                || vec![EdwardsPoint::identity(); buckets_count],
+                |buckets, digit_index| Pippenger::column(&scalars_points, digit_index, buckets),
+            )
+            .collect();
+
+        let mut columns = columns.into_iter().rev();
         let hi_column = columns.next().expect("should have more than zero digits");
 
         Some(columns.fold(hi_column, |total, p| total.mul_by_pow_2(w as u32) + p))
@@ -196,4 +260,24 @@ mod test {
             n /= 2;
         }
     }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn par_pippenger_matches_serial() {
+        let x = Scalar::from(2128506u64).invert();
+        let y = Scalar::from(4443282u64).invert();
+        // Cover each window width.
+        for n in [0, 1, 100, 600, 1000] {
+            let points: Vec<_> = (0..n)
+                .map(|i| constants::ED25519_BASEPOINT_POINT * Scalar::from(1 + i as u64))
+                .collect();
+            let scalars: Vec<_> = (0..n).map(|i| x + (Scalar::from(i as u64) * y)).collect();
+
+            let control = Pippenger::vartime_multiscalar_mul(&scalars, &points);
+            let subject =
+                Pippenger::par_optional_multiscalar_mul(&scalars, points.iter().map(|P| Some(*P)));
+
+            assert_eq!(subject, Some(control));
+        }
+    }
 }
@@ -124,8 +124,24 @@ impl<'b> MulAssign<&'b FieldElement2625> for FieldElement2625 {
 impl<'a, 'b> Mul<&'b FieldElement2625> for &'a FieldElement2625 {
     type Output = FieldElement2625;
 
-    #[rustfmt::skip] // keep alignment of z* calculations
     fn mul(self, _rhs: &'b FieldElement2625) -> FieldElement2625 {
+        FieldElement2625::mul_with_addend(self, _rhs, &[0; 10])
+    }
+}
+
+impl FieldElement2625 {
+    /// Compute `self * b + c`, adding `c` into the wide products before
+    /// the carry chain, so that the result is only reduced once.
+    #[cfg(feature = "hazmat")]
+    pub fn mul_add(&self, b: &FieldElement2625, c: &FieldElement2625) -> FieldElement2625 {
+        FieldElement2625::mul_with_addend(self, b, &c.0)
+    }
+
+    /// Compute `x * y + addend`, where the limbs of `addend` are bounded
+    /// like those of `x` and `y`.
+    #[rustfmt::skip] // keep alignment of z* calculations
+    #[inline(always)]
+    fn mul_with_addend(x: &FieldElement2625, y: &FieldElement2625, addend: &[u32; 10]) -> FieldElement2625 {
         /// Helper function to multiply two 32-bit integers with 64 bits
         /// of output.
         #[inline(always)]
@@ -133,9 +149,9 @@ impl<'a, 'b> Mul<&'b FieldElement2625> for &'a FieldElement2625 {
             (x as u64) * (y as u64)
         }
 
-        // Alias self, _rhs for more readable formulas
-        let x: &[u32; 10] = &self.0;
-        let y: &[u32; 10] = &_rhs.0;
+        // Alias the inputs for more readable formulas
+        let x: &[u32; 10] = &x.0;
+        let y: &[u32; 10] = &y.0;
 
         // We assume that the input limbs x[i], y[i] are bounded by:
         //
@@ -223,7 +239,16 @@ impl<'a, 'b> Mul<&'b FieldElement2625> for &'a FieldElement2625 {
         //
         // So z[0] fits into a u64 if 51 + 2*b + lg(249) < 64
         //                         if b < 2.5.
-        FieldElement2625::reduce([z0, z1, z2, z3, z4, z5, z6, z7, z8, z9])
+        //
+        // The addend's limbs are less than 2^(26 + b) < 2^29, and
+        // 249*2^56 + 2^29 < 2^64, so adding them does not overflow.
+        FieldElement2625::reduce([
+            z0 + (addend[0] as u64), z1 + (addend[1] as u64),
+            z2 + (addend[2] as u64), z3 + (addend[3] as u64),
+            z4 + (addend[4] as u64), z5 + (addend[5] as u64),
+            z6 + (addend[6] as u64), z7 + (addend[7] as u64),
+            z8 + (addend[8] as u64), z9 + (addend[9] as u64),
+        ])
     }
 }
 
@@ -288,6 +313,23 @@ impl FieldElement2625 {
         FieldElement2625(limbs)
     }
 
+    /// Construct a `FieldElement2625` from its radix-\\( 2\^{25.5} \\)
+    /// limbs, so that the value is
+    /// \\( \sum\_{i=0}\^{9} \text{limbs}[i] \cdot 2\^{\lceil 25.5 i \rceil} \\).
+    ///
+    /// The limbs need not be reduced, but even-indexed limbs must be less
+    /// than \\( 2\^{27} \\) and odd-indexed limbs less than \\( 2\^{26} \\).
+    /// These bounds are only checked in debug builds.
+    #[cfg(feature = "hazmat")]
+    pub const fn from_raw_limbs(limbs: [u32; 10]) -> FieldElement2625 {
+        let mut i = 0;
+        while i < 10 {
+            debug_assert!(limbs[i] < (1 << (27 - (i % 2))), "limb out of range");
+            i += 1;
+        }
+        FieldElement2625::from_limbs(limbs)
+    }
+
     /// The scalar \\( 0 \\).
     pub const ZERO: FieldElement2625 = FieldElement2625::from_limbs([0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
     /// The scalar \\( 1 \\).
@@ -317,7 +359,7 @@ impl FieldElement2625 {
     }
 
     /// Given `k > 0`, return `self^(2^k)`.
-    pub fn pow2k(&self, k: u32) -> FieldElement2625 {
+    pub(crate) fn pow2k(&self, k: u32) -> FieldElement2625 {
         debug_assert!(k > 0);
         let mut z = self.square();
         for _ in 1..k {
@@ -13,6 +13,8 @@
 use core::fmt::Debug;
 use core::ops::{Index, IndexMut};
 
+use subtle::{Choice, ConditionallySelectable};
+
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
@@ -36,6 +38,16 @@ impl Zeroize for Scalar29 {
     }
 }
 
+impl ConditionallySelectable for Scalar29 {
+    fn conditional_select(a: &Scalar29, b: &Scalar29, choice: Choice) -> Scalar29 {
+        let mut limbs = a.0;
+        for (limb, b_limb) in limbs.iter_mut().zip(b.0.iter()) {
+            limb.conditional_assign(b_limb, choice);
+        }
+        Scalar29(limbs)
+    }
+}
+
 impl Index<usize> for Scalar29 {
     type Output = u32;
     fn index(&self, _index: usize) -> &u32 {
@@ -87,7 +99,6 @@ impl Scalar29 {
     }
 
     /// Reduce a 64 byte / 512 bit scalar mod l.
-    #[rustfmt::skip] // keep alignment of lo[*] calculations
     pub fn from_bytes_wide(bytes: &[u8; 64]) -> Scalar29 {
         let mut words = [0u32; 16];
         for i in 0..16 {
@@ -96,6 +107,23 @@ impl Scalar29 {
             }
         }
 
+        Scalar29::from_u32_words_wide(&words)
+    }
+
+    /// Reduce a 512 bit scalar, given as eight little-endian 64-bit words, mod l.
+    pub fn from_words_wide(words: &[u64; 8]) -> Scalar29 {
+        let mut halves = [0u32; 16];
+        for (i, word) in words.iter().enumerate() {
+            halves[2 * i] = *word as u32;
+            halves[2 * i + 1] = (*word >> 32) as u32;
+        }
+
+        Scalar29::from_u32_words_wide(&halves)
+    }
+
+    /// Reduce a 512 bit scalar, given as sixteen little-endian 32-bit words, mod l.
+    #[rustfmt::skip] // keep alignment of lo[*] and hi[*] calculations
+    fn from_u32_words_wide(words: &[u32; 16]) -> Scalar29 {
         let mask = (1u32 << 29) - 1;
         let mut lo = Scalar29::ZERO;
         let mut hi = Scalar29::ZERO;
@@ -355,6 +383,25 @@ impl Scalar29 {
         Scalar29::montgomery_reduce(&Scalar29::mul_internal(&ab, &constants::RR))
     }
 
+    /// Compute `a * b + c` (mod l), folding the addition into the final
+    /// Montgomery reduction.
+    #[inline(never)]
+    pub fn mul_add(a: &Scalar29, b: &Scalar29, c: &Scalar29) -> Scalar29 {
+        let ab = Scalar29::montgomery_reduce(&Scalar29::mul_internal(a, b));
+        let mut limbs = Scalar29::mul_internal(&ab, &constants::RR);
+
+        // Add c * R, where R = 2^261 is nine limbs.  There is no eighteenth
+        // limb, so the top limb of c is shifted into the last limb instead.
+        for i in 0..8 {
+            limbs[i + 9] += c[i] as u64;
+        }
+        limbs[16] += (c[8] as u64) << 29;
+
+        // The reduction of (ab R^2 / R + c R) / R is less than 2l + l/512, so
+        // one subtraction may remain after the one in montgomery_reduce.
+        Scalar29::sub(&Scalar29::montgomery_reduce(&limbs), &constants::L)
+    }
+
     /// Compute `a^2` (mod l).
     #[inline(never)]
     #[allow(dead_code)] // XXX we don't expose square() via the Scalar API
@@ -111,16 +111,32 @@ impl<'b> MulAssign<&'b FieldElement51> for FieldElement51 {
 impl<'a, 'b> Mul<&'b FieldElement51> for &'a FieldElement51 {
     type Output = FieldElement51;
 
-    #[rustfmt::skip] // keep alignment of c* calculations
     fn mul(self, _rhs: &'b FieldElement51) -> FieldElement51 {
+        FieldElement51::mul_with_addend(self, _rhs, &[0; 5])
+    }
+}
+
+impl FieldElement51 {
+    /// Compute `self * b + c`, adding `c` into the wide products before
+    /// the carry chain, so that the result is only reduced once.
+    #[cfg(feature = "hazmat")]
+    pub fn mul_add(&self, b: &FieldElement51, c: &FieldElement51) -> FieldElement51 {
+        FieldElement51::mul_with_addend(self, b, &c.0)
+    }
+
+    /// Compute `a * b + addend`, where the limbs of `addend` are bounded
+    /// like those of `a` and `b`.
+    #[rustfmt::skip] // keep alignment of c* calculations
+    #[inline(always)]
+    fn mul_with_addend(a: &FieldElement51, b: &FieldElement51, addend: &[u64; 5]) -> FieldElement51 {
         /// Helper function to multiply two 64-bit integers with 128
         /// bits of output.
         #[inline(always)]
         fn m(x: u64, y: u64) -> u128 { (x as u128) * (y as u128) }
 
-        // Alias self, _rhs for more readable formulas
-        let a: &[u64; 5] = &self.0;
-        let b: &[u64; 5] = &_rhs.0;
+        // Alias the inputs for more readable formulas
+        let a: &[u64; 5] = &a.0;
+        let b: &[u64; 5] = &b.0;
 
         // Precondition: assume input limbs a[i], b[i] are bounded as
         //
@@ -147,6 +163,14 @@ impl<'a, 'b> Mul<&'b FieldElement51> for &'a FieldElement51 {
         let mut c3: u128 = m(a[3], b[0]) + m(a[2],  b[1]) + m(a[1],  b[2]) + m(a[0],  b[3]) + m(a[4], b4_19);
         let mut c4: u128 = m(a[4], b[0]) + m(a[3],  b[1]) + m(a[2],  b[2]) + m(a[1],  b[3]) + m(a[0] , b[4]);
 
+        // Add the addend, whose limbs are less than 2^(51 + b).  This is
+        // negligible next to the bounds on the c[i] below.
+        let c0 = c0 + (addend[0] as u128);
+        c1 += addend[1] as u128;
+        c2 += addend[2] as u128;
+        c3 += addend[3] as u128;
+        c4 += addend[4] as u128;
+
         // How big are the c[i]? We have
         //
         //    c[i] < 2^(102 + 2*b) * (1+i + (4-i)*19)
@@ -164,6 +188,7 @@ impl<'a, 'b> Mul<&'b FieldElement51> for &'a FieldElement51 {
         debug_assert!(a[2] < (1 << 54)); debug_assert!(b[2] < (1 << 54));
         debug_assert!(a[3] < (1 << 54)); debug_assert!(b[3] < (1 << 54));
         debug_assert!(a[4] < (1 << 54)); debug_assert!(b[4] < (1 << 54));
+        debug_assert!(addend.iter().all(|&limb| limb < (1 << 54)));
 
         // Casting to u64 and back tells the compiler that the carry is
         // bounded by 2^64, so that the addition is a u128 + u64 rather
@@ -259,6 +284,22 @@ impl FieldElement51 {
         FieldElement51(limbs)
     }
 
+    /// Construct a `FieldElement51` from its radix-\\( 2\^{51} \\) limbs,
+    /// so that the value is
+    /// \\( \sum\_{i=0}\^{4} \text{limbs}[i] \cdot 2\^{51 i} \\).
+    ///
+    /// The limbs need not be reduced, but each must be less than
+    /// \\( 2\^{52} \\).  This bound is only checked in debug builds.
+    #[cfg(feature = "hazmat")]
+    pub const fn from_raw_limbs(limbs: [u64; 5]) -> FieldElement51 {
+        let mut i = 0;
+        while i < 5 {
+            debug_assert!(limbs[i] < (1 << 52), "limb out of range");
+            i += 1;
+        }
+        FieldElement51::from_limbs(limbs)
+    }
+
     /// The scalar \\( 0 \\).
     pub const ZERO: FieldElement51 = FieldElement51::from_limbs([0, 0, 0, 0, 0]);
     /// The scalar \\( 1 \\).
@@ -451,7 +492,7 @@ impl FieldElement51 {
 
     /// Given `k > 0`, return `self^(2^k)`.
     #[rustfmt::skip] // keep alignment of c* calculations
-    pub fn pow2k(&self, mut k: u32) -> FieldElement51 {
+    pub(crate) fn pow2k(&self, mut k: u32) -> FieldElement51 {
 
         debug_assert!( k > 0 );
 
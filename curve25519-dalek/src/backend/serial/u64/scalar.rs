@@ -14,6 +14,8 @@
 use core::fmt::Debug;
 use core::ops::{Index, IndexMut};
 
+use subtle::{Choice, ConditionallySelectable};
+
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
@@ -37,6 +39,16 @@ impl Zeroize for Scalar52 {
     }
 }
 
+impl ConditionallySelectable for Scalar52 {
+    fn conditional_select(a: &Scalar52, b: &Scalar52, choice: Choice) -> Scalar52 {
+        let mut limbs = a.0;
+        for (limb, b_limb) in limbs.iter_mut().zip(b.0.iter()) {
+            limb.conditional_assign(b_limb, choice);
+        }
+        Scalar52(limbs)
+    }
+}
+
 impl Index<usize> for Scalar52 {
     type Output = u64;
     fn index(&self, _index: usize) -> &u64 {
@@ -84,7 +96,6 @@ impl Scalar52 {
     }
 
     /// Reduce a 64 byte / 512 bit scalar mod l
-    #[rustfmt::skip] // keep alignment of lo[*] and hi[*] calculations
     pub fn from_bytes_wide(bytes: &[u8; 64]) -> Scalar52 {
         let mut words = [0u64; 8];
         for i in 0..8 {
@@ -93,6 +104,12 @@ impl Scalar52 {
             }
         }
 
+        Scalar52::from_words_wide(&words)
+    }
+
+    /// Reduce a 512 bit scalar, given as eight little-endian 64-bit words, mod l
+    #[rustfmt::skip] // keep alignment of lo[*] and hi[*] calculations
+    pub fn from_words_wide(words: &[u64; 8]) -> Scalar52 {
         let mask = (1u64 << 52) - 1;
         let mut lo = Scalar52::ZERO;
         let mut hi = Scalar52::ZERO;
@@ -283,6 +300,25 @@ impl Scalar52 {
         Scalar52::montgomery_reduce(&Scalar52::mul_internal(&ab, &constants::RR))
     }
 
+    /// Compute `a * b + c` (mod l), folding the addition into the final
+    /// Montgomery reduction.
+    #[inline(never)]
+    pub fn mul_add(a: &Scalar52, b: &Scalar52, c: &Scalar52) -> Scalar52 {
+        let ab = Scalar52::montgomery_reduce(&Scalar52::mul_internal(a, b));
+        let mut limbs = Scalar52::mul_internal(&ab, &constants::RR);
+
+        // Add c * R, where R = 2^260 is five limbs.  There is no sixth limb,
+        // so the top limb of c is shifted into the last limb instead.
+        for i in 0..4 {
+            limbs[i + 5] += c[i] as u128;
+        }
+        limbs[8] += (c[4] as u128) << 52;
+
+        // The reduction of (ab R^2 / R + c R) / R is less than 2l + l/256, so
+        // one subtraction may remain after the one in montgomery_reduce.
+        Scalar52::sub(&Scalar52::montgomery_reduce(&limbs), &constants::L)
+    }
+
     /// Compute `a^2` (mod l)
     #[inline(never)]
     #[allow(dead_code)] // XXX we don't expose square() via the Scalar API
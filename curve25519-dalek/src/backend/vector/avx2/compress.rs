@@ -0,0 +1,97 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Batch compression of Edwards points.
+//!
+//! Compressing a point divides by its \\(Z\\) coordinate, so a batch of
+//! points can share a single inversion using Montgomery's trick.  Here the
+//! points are dealt round-robin into four lanes, and each lane keeps its
+//! own running product, so that both the prefix products and the final
+//! multiplications are vectorized.  The four lane products are then
+//! inverted together with one field inversion.
+
+#![allow(non_snake_case)]
+
+use alloc::vec::Vec;
+
+use curve25519_dalek_derive::unsafe_target_feature;
+
+use super::field::FieldElement2625x4;
+use crate::backend::serial::u64::field::FieldElement51;
+use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
+use crate::traits::Identity;
+
+/// Compress each of `points`, sharing one field inversion between them.
+#[unsafe_target_feature("avx2")]
+pub fn compress_batch(points: &[&EdwardsPoint]) -> Vec<CompressedEdwardsY> {
+    // Pad the last chunk with the identity, whose Z coordinate is one.
+    let identity = EdwardsPoint::identity();
+    let chunk =
+        |k: usize| [0, 1, 2, 3].map(|j| points.get(4 * k + j).copied().unwrap_or(&identity));
+    let chunks_count = (points.len() + 3) / 4;
+
+    let Z: Vec<FieldElement2625x4> = (0..chunks_count)
+        .map(|k| {
+            let P = chunk(k);
+            FieldElement2625x4::new(&P[0].Z, &P[1].Z, &P[2].Z, &P[3].Z)
+        })
+        .collect();
+
+    // Pass through the chunks, recording the product of the earlier Z
+    // coordinates in each lane.
+    let mut acc = FieldElement2625x4::splat(&FieldElement51::ONE);
+    let mut scratch = Vec::with_capacity(chunks_count);
+    for z in Z.iter() {
+        scratch.push(acc);
+        acc = &acc * z;
+    }
+
+    // Invert the product in each lane.  Z coordinates are nonzero, so
+    // none of the products are zero.
+    let mut acc_inv = acc.split();
+    FieldElement51::batch_invert_array(&mut acc_inv);
+    let mut acc = FieldElement2625x4::new(&acc_inv[0], &acc_inv[1], &acc_inv[2], &acc_inv[3]);
+
+    // Pass through the chunks backwards to recover each 1/Z, and use it
+    // to compute the affine coordinates.
+    let mut compressed = Vec::with_capacity(chunks_count * 4);
+    for k in (0..chunks_count).rev() {
+        let Zinv = &acc * &scratch[k];
+        acc = &acc * &Z[k];
+
+        let P = chunk(k);
+        let X = FieldElement2625x4::new(&P[0].X, &P[1].X, &P[2].X, &P[3].X);
+        let Y = FieldElement2625x4::new(&P[0].Y, &P[1].Y, &P[2].Y, &P[3].Y);
+        let x = (&X * &Zinv).split();
+        let y = (&Y * &Zinv).split();
+        for j in (0..4).rev() {
+            compressed.push(CompressedEdwardsY::from_affine(&x[j], &y[j]));
+        }
+    }
+    compressed.reverse();
+    compressed.truncate(points.len());
+    compressed
+}
+
+#[cfg(target_feature = "avx2")]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constants;
+    use crate::scalar::Scalar;
+
+    #[test]
+    fn compress_batch_matches_compress() {
+        let mut rng = rand::thread_rng();
+        for n in 0..10 {
+            let points: Vec<EdwardsPoint> = (0..n)
+                .map(|_| constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng))
+                .collect();
+            let refs: Vec<&EdwardsPoint> = points.iter().collect();
+            let expected: Vec<CompressedEdwardsY> = points.iter().map(|P| P.compress()).collect();
+            assert_eq!(compress_batch(&refs), expected);
+        }
+    }
+}
@@ -112,13 +112,21 @@ fn repack_pair(x: u32x8, y: u32x8) -> u32x8 {
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone, Debug)]
 pub enum Lanes {
+    /// Lane C.
     C,
+    /// Lane D.
     D,
+    /// Lanes A and B.
     AB,
+    /// Lanes A and C.
     AC,
+    /// Lanes C and D.
     CD,
+    /// Lanes A and D.
     AD,
+    /// Lanes B and C.
     BC,
+    /// Lanes A, B, C and D.
     ABCD,
 }
 
@@ -130,15 +138,25 @@ pub enum Lanes {
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone, Debug)]
 pub enum Shuffle {
+    /// \\( (A, B, C, D) \\mapsto (A, A, A, A) \\).
     AAAA,
+    /// \\( (A, B, C, D) \\mapsto (B, B, B, B) \\).
     BBBB,
+    /// \\( (A, B, C, D) \\mapsto (C, A, C, A) \\).
     CACA,
+    /// \\( (A, B, C, D) \\mapsto (D, B, B, D) \\).
     DBBD,
+    /// \\( (A, B, C, D) \\mapsto (A, D, D, A) \\).
     ADDA,
+    /// \\( (A, B, C, D) \\mapsto (C, B, C, B) \\).
     CBCB,
+    /// \\( (A, B, C, D) \\mapsto (A, B, A, B) \\).
     ABAB,
+    /// \\( (A, B, C, D) \\mapsto (B, A, D, C) \\).
     BADC,
+    /// \\( (A, B, C, D) \\mapsto (B, A, C, D) \\).
     BACD,
+    /// \\( (A, B, C, D) \\mapsto (A, B, D, C) \\).
     ABDC,
 }
 
@@ -186,6 +204,7 @@ impl ConditionallySelectable for FieldElement2625x4 {
 
 #[unsafe_target_feature("avx2")]
 impl FieldElement2625x4 {
+    /// The vector \\((0, 0, 0, 0)\\).
     pub const ZERO: FieldElement2625x4 = FieldElement2625x4([u32x8::splat_const::<0>(); 5]);
 
     /// Split this vector into an array of four (serial) field
@@ -321,6 +340,97 @@ impl FieldElement2625x4 {
         ])
     }
 
+    /// Swap the lanes of `a` and `b` for which the corresponding entry of
+    /// `choices` is set, in constant time.
+    pub fn conditional_swap_lanes(
+        a: &mut FieldElement2625x4,
+        b: &mut FieldElement2625x4,
+        choices: [Choice; 4],
+    ) {
+        let m = choices.map(|c| (-(c.unwrap_u8() as i32)) as u32);
+        // Each u32x8 holds limbs of lanes (A, B, A, B, C, D, C, D).
+        let mask_vec = u32x8::new(m[0], m[1], m[0], m[1], m[2], m[3], m[2], m[3]);
+        for i in 0..5 {
+            let t = mask_vec & (a.0[i] ^ b.0[i]);
+            a.0[i] ^= t;
+            b.0[i] ^= t;
+        }
+    }
+
+    /// Square each lane `k` times.
+    fn pow2k(&self, k: u32) -> FieldElement2625x4 {
+        let mut x = *self;
+        for _ in 0..k {
+            x = &x * &x;
+        }
+        x
+    }
+
+    /// Raise each lane to the power \\( (p-5)/8 = 2\^{252} - 3 \\).
+    ///
+    /// This uses the same addition chain as `FieldElement::pow_p58`.
+    ///
+    /// # Preconditions
+    ///
+    /// The coefficients of `self` must be bounded with \\( b < 1.75 \\).
+    ///
+    /// # Postconditions
+    ///
+    /// The coefficients of the result are bounded with \\( b < 0.007 \\).
+    #[rustfmt::skip] // keep alignment of explanatory comments
+    pub fn pow_p58(&self) -> FieldElement2625x4 {
+        let t0  = self * self;             // 1
+        let t1  = t0.pow2k(2);             // 3
+        let t2  = self * &t1;              // 3,0
+        let t3  = &t0 * &t2;               // 3,1,0
+        let t4  = &t3 * &t3;               // 4,2,1
+        let t5  = &t2 * &t4;               // 4,3,2,1,0
+        let t6  = t5.pow2k(5);             // 9,8,7,6,5
+        let t7  = &t6 * &t5;               // 9..0
+        let t8  = t7.pow2k(10);            // 19..10
+        let t9  = &t8 * &t7;               // 19..0
+        let t10 = t9.pow2k(20);            // 39..20
+        let t11 = &t10 * &t9;              // 39..0
+        let t12 = t11.pow2k(10);           // 49..10
+        let t13 = &t12 * &t7;              // 49..0
+        let t14 = t13.pow2k(50);           // 99..50
+        let t15 = &t14 * &t13;             // 99..0
+        let t16 = t15.pow2k(100);          // 199..100
+        let t17 = &t16 * &t15;             // 199..0
+        let t18 = t17.pow2k(50);           // 249..50
+        let t19 = &t18 * &t13;             // 249..0
+        let t20 = t19.pow2k(2);            // 251..2
+
+        self * &t20                        // 251..2,0
+    }
+
+    /// Compute `FieldElement::sqrt_ratio_i(&u[i], &v[i])` for four pairs
+    /// at once.
+    ///
+    /// The exponentiation is done in parallel lanes; only the final sign
+    /// and square checks are done on each element separately.
+    pub fn sqrt_ratio_i(
+        u: &[FieldElement51; 4],
+        v: &[FieldElement51; 4],
+    ) -> [(Choice, FieldElement51); 4] {
+        let u4 = FieldElement2625x4::new(&u[0], &u[1], &u[2], &u[3]);
+        let v4 = FieldElement2625x4::new(&v[0], &v[1], &v[2], &v[3]);
+
+        let v3 = &(&v4 * &v4) * &v4;
+        let v7 = &(&v3 * &v3) * &v4;
+        let r = &(&u4 * &v3) * &(&u4 * &v7).pow_p58();
+        let check = &v4 * &(&r * &r);
+
+        let r = r.split();
+        let check = check.split();
+        [
+            FieldElement51::sqrt_ratio_i_finish(&u[0], r[0], &check[0]),
+            FieldElement51::sqrt_ratio_i_finish(&u[1], r[1], &check[1]),
+            FieldElement51::sqrt_ratio_i_finish(&u[2], r[2], &check[2]),
+            FieldElement51::sqrt_ratio_i_finish(&u[3], r[3], &check[3]),
+        ]
+    }
+
     /// Convenience wrapper around `new(x,x,x,x)`.
     pub fn splat(x: &FieldElement51) -> FieldElement2625x4 {
         FieldElement2625x4::new(x, x, x, x)
@@ -980,4 +1090,23 @@ mod test {
         assert_eq!(x2, splits[2]);
         assert_eq!(x3, splits[3]);
     }
+
+    #[test]
+    fn sqrt_ratio_i_vs_serial() {
+        let a = FieldElement51([2438, 24, 243, 0, 0]).invert();
+        let b = FieldElement51([10000, 10001, 10002, 10003, 10004]);
+        let two = FieldElement51([2, 0, 0, 0, 0]);
+        let zero = FieldElement51::ZERO;
+
+        // Cover squares, nonsquares, and zero numerators and denominators.
+        let u = [a.square(), two, zero, a];
+        let v = [b, FieldElement51::ONE, b, zero];
+
+        let result = FieldElement2625x4::sqrt_ratio_i(&u, &v);
+        for i in 0..4 {
+            let (expected_choice, expected_root) = FieldElement51::sqrt_ratio_i(&u[i], &v[i]);
+            assert_eq!(bool::from(result[i].0), bool::from(expected_choice));
+            assert_eq!(result[i].1, expected_root);
+        }
+    }
 }
@@ -11,10 +11,15 @@
 
 #![doc = include_str!("../../../../docs/avx2-notes.md")]
 
-pub(crate) mod field;
+pub mod field;
 
 pub(crate) mod edwards;
 
+pub(crate) mod montgomery;
+
+#[cfg(feature = "alloc")]
+pub(crate) mod compress;
+
 pub(crate) mod constants;
 
 pub(crate) use self::edwards::{CachedPoint, ExtendedPoint};
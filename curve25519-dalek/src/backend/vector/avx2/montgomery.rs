@@ -0,0 +1,145 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! A 4-way parallel Montgomery ladder.
+//!
+//! Each lane of a `FieldElement2625x4` carries the state of an
+//! independent ladder, so four variable-base X25519 operations proceed in
+//! lockstep.  The ladder step is the one used by the serial
+//! implementation in the `montgomery` module, and the per-lane
+//! conditional swaps are done with masks so that the whole computation
+//! is constant-time in all four scalars.
+
+#![allow(non_snake_case)]
+
+use subtle::Choice;
+
+use curve25519_dalek_derive::unsafe_target_feature;
+
+use super::field::FieldElement2625x4;
+use crate::backend::serial::u64::field::FieldElement51;
+use crate::montgomery::MontgomeryPoint;
+use crate::scalar::Scalar;
+
+/// Given the \\(u\\)-coordinates \\(u\_i = u(P\_i)\\) of four points and
+/// four scalars \\(n\_i < 2\^{255}\\), compute \\(u(\[n\_i\]P\_i)\\).
+#[unsafe_target_feature("avx2")]
+pub fn mul_x4(points: &[MontgomeryPoint; 4], scalars: &[Scalar; 4]) -> [MontgomeryPoint; 4] {
+    let u = points.map(|p| FieldElement51::from_bytes(&p.0));
+    let affine_u = FieldElement2625x4::new(&u[0], &u[1], &u[2], &u[3]);
+
+    // (U0 : W0) starts at the identity, (U1 : W1) at the input point.
+    let mut U0 = FieldElement2625x4::splat(&FieldElement51::ONE);
+    let mut W0 = FieldElement2625x4::ZERO;
+    let mut U1 = affine_u;
+    let mut W1 = FieldElement2625x4::splat(&FieldElement51::ONE);
+
+    let bit = |n: &Scalar, i: usize| (n.bytes[i >> 3] >> (i & 7)) & 1;
+
+    // By scalar invariant #1, bit 255 is zero, so start at bit 254.
+    let mut prev_bits = [0u8; 4];
+    for i in (0..255).rev() {
+        let cur_bits = [
+            bit(&scalars[0], i),
+            bit(&scalars[1], i),
+            bit(&scalars[2], i),
+            bit(&scalars[3], i),
+        ];
+        let choices = [
+            Choice::from(prev_bits[0] ^ cur_bits[0]),
+            Choice::from(prev_bits[1] ^ cur_bits[1]),
+            Choice::from(prev_bits[2] ^ cur_bits[2]),
+            Choice::from(prev_bits[3] ^ cur_bits[3]),
+        ];
+        FieldElement2625x4::conditional_swap_lanes(&mut U0, &mut U1, choices);
+        FieldElement2625x4::conditional_swap_lanes(&mut W0, &mut W1, choices);
+        differential_add_and_double(&mut U0, &mut W0, &mut U1, &mut W1, &affine_u);
+        prev_bits = cur_bits;
+    }
+    let choices = prev_bits.map(Choice::from);
+    FieldElement2625x4::conditional_swap_lanes(&mut U0, &mut U1, choices);
+    FieldElement2625x4::conditional_swap_lanes(&mut W0, &mut W1, choices);
+    // Don't leave the bits on the stack
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(&mut prev_bits);
+
+    let U = U0.split();
+    let mut W = W0.split();
+    FieldElement51::batch_invert_array(&mut W);
+    [
+        MontgomeryPoint((&U[0] * &W[0]).as_bytes()),
+        MontgomeryPoint((&U[1] * &W[1]).as_bytes()),
+        MontgomeryPoint((&U[2] * &W[2]).as_bytes()),
+        MontgomeryPoint((&U[3] * &W[3]).as_bytes()),
+    ]
+}
+
+/// Perform the double-and-add step of the Montgomery ladder in each lane.
+///
+/// This follows `montgomery::differential_add_and_double`; the comments
+/// give the bounds on the coefficients of each intermediate value.
+#[unsafe_target_feature("avx2")]
+#[rustfmt::skip] // keep alignment of explanatory comments
+#[inline(always)]
+fn differential_add_and_double(
+    U_P: &mut FieldElement2625x4,
+    W_P: &mut FieldElement2625x4,
+    U_Q: &mut FieldElement2625x4,
+    W_Q: &mut FieldElement2625x4,
+    affine_PmQ: &FieldElement2625x4,
+) {
+    let t0 = *U_P + *W_P;                // b < 1.01
+    let t1 = *U_P + W_P.negate_lazy();   // b < 1.6
+    let t2 = *U_Q + *W_Q;                // b < 1.01
+    let t3 = *U_Q + W_Q.negate_lazy();   // b < 1.6
+
+    let t4 = &t0 * &t0;                  // b < 0.007
+    let t5 = &t1 * &t1;                  // b < 0.007
+
+    let t6 = t4 + t5.negate_lazy();      // b < 1.6
+
+    let t7 = &t0 * &t3;                  // b < 0.007
+    let t8 = &t1 * &t2;                  // b < 0.007
+
+    let t9  = t7 + t8;                   // b < 1.01
+    let t10 = t7 + t8.negate_lazy();     // b < 1.6
+
+    let t11 = &t9 * &t9;                 // b < 0.007
+    let t12 = &t10 * &t10;               // b < 0.007
+
+    let t13 = t6 * (121666, 121666, 121666, 121666); // b < 0.007
+
+    let t14 = &t4 * &t5;                 // b < 0.007
+    let t15 = t13 + t5;                  // b < 1.01
+
+    let t16 = &t6 * &t15;                // b < 0.007
+
+    let t17 = affine_PmQ * &t12;         // b < 0.007
+    let t18 = t11;                       // b < 0.007
+
+    *U_P = t14;
+    *W_P = t16;
+    *U_Q = t18;
+    *W_Q = t17;
+}
+
+#[cfg(target_feature = "avx2")]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constants;
+
+    #[test]
+    fn mul_x4_matches_serial() {
+        let mut rng = rand::thread_rng();
+        let points = [0, 1, 2, 3].map(|_| {
+            (constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng)).to_montgomery()
+        });
+        let scalars = [0, 1, 2, 3].map(|_| Scalar::random(&mut rng));
+
+        let expected = [0, 1, 2, 3].map(|i| points[i] * scalars[i]);
+        assert_eq!(mul_x4(&points, &scalars), expected);
+    }
+}
@@ -0,0 +1,98 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Batch compression of Edwards points.
+//!
+//! See the `avx2` version of this module for details.
+
+#![allow(non_snake_case)]
+
+use alloc::vec::Vec;
+
+use curve25519_dalek_derive::unsafe_target_feature;
+
+use super::field::{F51x4Reduced, F51x4Unreduced};
+use crate::backend::serial::u64::field::FieldElement51;
+use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
+use crate::traits::Identity;
+
+/// Compress each of `points`, sharing one field inversion between them.
+#[unsafe_target_feature("avx512ifma,avx512vl")]
+pub fn compress_batch(points: &[&EdwardsPoint]) -> Vec<CompressedEdwardsY> {
+    // Pad the last chunk with the identity, whose Z coordinate is one.
+    let identity = EdwardsPoint::identity();
+    let chunk =
+        |k: usize| [0, 1, 2, 3].map(|j| points.get(4 * k + j).copied().unwrap_or(&identity));
+    let chunks_count = (points.len() + 3) / 4;
+
+    let new = |a: &FieldElement51, b: &FieldElement51, c: &FieldElement51, d: &FieldElement51| {
+        F51x4Reduced::from(F51x4Unreduced::new(a, b, c, d))
+    };
+    let mul = |a: &F51x4Reduced, b: &F51x4Reduced| F51x4Reduced::from(a * b);
+
+    let Z: Vec<F51x4Reduced> = (0..chunks_count)
+        .map(|k| {
+            let P = chunk(k);
+            new(&P[0].Z, &P[1].Z, &P[2].Z, &P[3].Z)
+        })
+        .collect();
+
+    // Pass through the chunks, recording the product of the earlier Z
+    // coordinates in each lane.
+    let one = &FieldElement51::ONE;
+    let mut acc = new(one, one, one, one);
+    let mut scratch = Vec::with_capacity(chunks_count);
+    for z in Z.iter() {
+        scratch.push(acc);
+        acc = mul(&acc, z);
+    }
+
+    // Invert the product in each lane.  Z coordinates are nonzero, so
+    // none of the products are zero.
+    let mut acc_inv = F51x4Unreduced::from(acc).split();
+    FieldElement51::batch_invert_array(&mut acc_inv);
+    let mut acc = new(&acc_inv[0], &acc_inv[1], &acc_inv[2], &acc_inv[3]);
+
+    // Pass through the chunks backwards to recover each 1/Z, and use it
+    // to compute the affine coordinates.
+    let mut compressed = Vec::with_capacity(chunks_count * 4);
+    for k in (0..chunks_count).rev() {
+        let Zinv = mul(&acc, &scratch[k]);
+        acc = mul(&acc, &Z[k]);
+
+        let P = chunk(k);
+        let X = new(&P[0].X, &P[1].X, &P[2].X, &P[3].X);
+        let Y = new(&P[0].Y, &P[1].Y, &P[2].Y, &P[3].Y);
+        let x = (&X * &Zinv).split();
+        let y = (&Y * &Zinv).split();
+        for j in (0..4).rev() {
+            compressed.push(CompressedEdwardsY::from_affine(&x[j], &y[j]));
+        }
+    }
+    compressed.reverse();
+    compressed.truncate(points.len());
+    compressed
+}
+
+#[cfg(all(target_feature = "avx512ifma", target_feature = "avx512vl"))]
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constants;
+    use crate::scalar::Scalar;
+
+    #[test]
+    fn compress_batch_matches_compress() {
+        let mut rng = rand::thread_rng();
+        for n in 0..10 {
+            let points: Vec<EdwardsPoint> = (0..n)
+                .map(|_| constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng))
+                .collect();
+            let refs: Vec<&EdwardsPoint> = points.iter().collect();
+            let expected: Vec<CompressedEdwardsY> = points.iter().map(|P| P.compress()).collect();
+            assert_eq!(compress_batch(&refs), expected);
+        }
+    }
+}
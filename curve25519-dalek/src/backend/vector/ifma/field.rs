@@ -9,6 +9,15 @@
 // - isis agora lovecruft <isis@patternsinthevoid.net>
 // - Henry de Valence <hdevalence@hdevalence.ca>
 
+//! An implementation of 4-way vectorized 52bit field arithmetic using
+//! AVX512 IFMA.
+//!
+//! Field elements are stored in radix \\(2\^{51}\\), one limb of each
+//! of the four lanes per `u64x4`.  An `F51x4Unreduced` supports cheap
+//! additions and lazy negation; it must be converted to an
+//! `F51x4Reduced` before it can be multiplied or squared, and products
+//! are returned unreduced.
+
 #![allow(non_snake_case)]
 
 use crate::backend::vector::packed_simd::u64x4;
@@ -42,18 +51,30 @@ pub struct F51x4Unreduced(pub(crate) [u64x4; 5]);
 #[derive(Copy, Clone, Debug)]
 pub struct F51x4Reduced(pub(crate) [u64x4; 5]);
 
+/// A shuffle of the lanes \\((A, B, C, D)\\) of a vector of field
+/// elements, named by its result.
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone)]
 pub enum Shuffle {
+    /// \\( (A, B, C, D) \\mapsto (A, A, A, A) \\).
     AAAA,
+    /// \\( (A, B, C, D) \\mapsto (B, B, B, B) \\).
     BBBB,
+    /// \\( (A, B, C, D) \\mapsto (B, A, D, C) \\).
     BADC,
+    /// \\( (A, B, C, D) \\mapsto (B, A, C, D) \\).
     BACD,
+    /// \\( (A, B, C, D) \\mapsto (A, D, D, A) \\).
     ADDA,
+    /// \\( (A, B, C, D) \\mapsto (C, B, C, B) \\).
     CBCB,
+    /// \\( (A, B, C, D) \\mapsto (A, B, D, C) \\).
     ABDC,
+    /// \\( (A, B, C, D) \\mapsto (A, B, A, B) \\).
     ABAB,
+    /// \\( (A, B, C, D) \\mapsto (D, B, B, D) \\).
     DBBD,
+    /// \\( (A, B, C, D) \\mapsto (C, A, C, A) \\).
     CACA,
 }
 
@@ -78,14 +99,22 @@ fn shuffle_lanes(x: u64x4, control: Shuffle) -> u64x4 {
     }
 }
 
+/// A subset of the lanes \\((A, B, C, D)\\) of a vector of field
+/// elements, used to specify blends.
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone)]
 pub enum Lanes {
+    /// Lane D.
     D,
+    /// Lane C.
     C,
+    /// Lanes A and B.
     AB,
+    /// Lanes A and C.
     AC,
+    /// Lanes A and D.
     AD,
+    /// Lanes B, C and D.
     BCD,
 }
 
@@ -108,8 +137,10 @@ fn blend_lanes(x: u64x4, y: u64x4, control: Lanes) -> u64x4 {
 
 #[unsafe_target_feature("avx512ifma,avx512vl")]
 impl F51x4Unreduced {
+    /// The vector \\((0, 0, 0, 0)\\).
     pub const ZERO: F51x4Unreduced = F51x4Unreduced([u64x4::splat_const::<0>(); 5]);
 
+    /// Create a vector from four field elements.
     pub fn new(
         x0: &FieldElement51,
         x1: &FieldElement51,
@@ -125,6 +156,7 @@ impl F51x4Unreduced {
         ])
     }
 
+    /// Split the vector into its four field elements.
     pub fn split(&self) -> [FieldElement51; 4] {
         let x = &self.0;
         [
@@ -159,6 +191,7 @@ impl F51x4Unreduced {
         ]
     }
 
+    /// Given \\((A, B, C, D)\\), compute \\((B - A, B + A, D - C, D + C)\\).
     #[inline]
     pub fn diff_sum(&self) -> F51x4Unreduced {
         // tmp1 = (B, A, D, C)
@@ -169,6 +202,7 @@ impl F51x4Unreduced {
         tmp1 + tmp2
     }
 
+    /// Negate each lane, without reducing the result.
     #[inline]
     pub fn negate_lazy(&self) -> F51x4Unreduced {
         let lo = u64x4::splat(36028797018963664u64);
@@ -182,6 +216,7 @@ impl F51x4Unreduced {
         ])
     }
 
+    /// Permute the lanes according to `control`.
     #[inline]
     pub fn shuffle(&self, control: Shuffle) -> F51x4Unreduced {
         F51x4Unreduced([
@@ -193,6 +228,8 @@ impl F51x4Unreduced {
         ])
     }
 
+    /// Take the lanes given by `control` from `other`, and the
+    /// remaining lanes from `self`.
     #[inline]
     pub fn blend(&self, other: &F51x4Unreduced, control: Lanes) -> F51x4Unreduced {
         F51x4Unreduced([
@@ -246,6 +283,24 @@ impl ConditionallySelectable for F51x4Reduced {
 
 #[unsafe_target_feature("avx512ifma,avx512vl")]
 impl F51x4Reduced {
+    /// Swap the lanes of `a` and `b` for which the corresponding entry of
+    /// `choices` is set, in constant time.
+    #[inline]
+    pub fn conditional_swap_lanes(
+        a: &mut F51x4Reduced,
+        b: &mut F51x4Reduced,
+        choices: [Choice; 4],
+    ) {
+        let m = choices.map(|c| (-(c.unwrap_u8() as i64)) as u64);
+        let mask_vec = u64x4::new(m[0], m[1], m[2], m[3]);
+        for i in 0..5 {
+            let t = mask_vec & (a.0[i] ^ b.0[i]);
+            a.0[i] ^= t;
+            b.0[i] ^= t;
+        }
+    }
+
+    /// Permute the lanes according to `control`.
     #[inline]
     pub fn shuffle(&self, control: Shuffle) -> F51x4Reduced {
         F51x4Reduced([
@@ -257,6 +312,8 @@ impl F51x4Reduced {
         ])
     }
 
+    /// Take the lanes given by `control` from `other`, and the
+    /// remaining lanes from `self`.
     #[inline]
     pub fn blend(&self, other: &F51x4Reduced, control: Lanes) -> F51x4Reduced {
         F51x4Reduced([
@@ -268,6 +325,7 @@ impl F51x4Reduced {
         ])
     }
 
+    /// Square each lane.
     #[inline]
     pub fn square(&self) -> F51x4Unreduced {
         unsafe {
@@ -381,6 +439,77 @@ impl F51x4Reduced {
             ])
         }
     }
+
+    /// Square each lane `k` times.
+    #[inline]
+    fn pow2k(&self, k: u32) -> F51x4Reduced {
+        let mut x = *self;
+        for _ in 0..k {
+            x = F51x4Reduced::from(x.square());
+        }
+        x
+    }
+
+    /// Raise each lane to the power \\( (p-5)/8 = 2\^{252} - 3 \\).
+    ///
+    /// This uses the same addition chain as `FieldElement::pow_p58`.
+    #[rustfmt::skip] // keep alignment of explanatory comments
+    pub fn pow_p58(&self) -> F51x4Reduced {
+        let mul = |a: &F51x4Reduced, b: &F51x4Reduced| F51x4Reduced::from(a * b);
+
+        let t0  = self.pow2k(1);           // 1
+        let t1  = t0.pow2k(2);             // 3
+        let t2  = mul(self, &t1);          // 3,0
+        let t3  = mul(&t0, &t2);           // 3,1,0
+        let t4  = t3.pow2k(1);             // 4,2,1
+        let t5  = mul(&t2, &t4);           // 4,3,2,1,0
+        let t6  = t5.pow2k(5);             // 9,8,7,6,5
+        let t7  = mul(&t6, &t5);           // 9..0
+        let t8  = t7.pow2k(10);            // 19..10
+        let t9  = mul(&t8, &t7);           // 19..0
+        let t10 = t9.pow2k(20);            // 39..20
+        let t11 = mul(&t10, &t9);          // 39..0
+        let t12 = t11.pow2k(10);           // 49..10
+        let t13 = mul(&t12, &t7);          // 49..0
+        let t14 = t13.pow2k(50);           // 99..50
+        let t15 = mul(&t14, &t13);         // 99..0
+        let t16 = t15.pow2k(100);          // 199..100
+        let t17 = mul(&t16, &t15);         // 199..0
+        let t18 = t17.pow2k(50);           // 249..50
+        let t19 = mul(&t18, &t13);         // 249..0
+        let t20 = t19.pow2k(2);            // 251..2
+
+        mul(self, &t20)                    // 251..2,0
+    }
+
+    /// Compute `FieldElement::sqrt_ratio_i(&u[i], &v[i])` for four pairs
+    /// at once.
+    ///
+    /// The exponentiation is done in parallel lanes; only the final sign
+    /// and square checks are done on each element separately.
+    pub fn sqrt_ratio_i(
+        u: &[FieldElement51; 4],
+        v: &[FieldElement51; 4],
+    ) -> [(Choice, FieldElement51); 4] {
+        let mul = |a: &F51x4Reduced, b: &F51x4Reduced| F51x4Reduced::from(a * b);
+
+        let u4 = F51x4Reduced::from(F51x4Unreduced::new(&u[0], &u[1], &u[2], &u[3]));
+        let v4 = F51x4Reduced::from(F51x4Unreduced::new(&v[0], &v[1], &v[2], &v[3]));
+
+        let v3 = mul(&v4.pow2k(1), &v4);
+        let v7 = mul(&v3.pow2k(1), &v4);
+        let r = mul(&mul(&u4, &v3), &mul(&u4, &v7).pow_p58());
+        let check = mul(&v4, &r.pow2k(1));
+
+        let r = F51x4Unreduced::from(r).split();
+        let check = F51x4Unreduced::from(check).split();
+        [
+            FieldElement51::sqrt_ratio_i_finish(&u[0], r[0], &check[0]),
+            FieldElement51::sqrt_ratio_i_finish(&u[1], r[1], &check[1]),
+            FieldElement51::sqrt_ratio_i_finish(&u[2], r[2], &check[2]),
+            FieldElement51::sqrt_ratio_i_finish(&u[3], r[3], &check[3]),
+        ]
+    }
 }
 
 #[unsafe_target_feature("avx512ifma,avx512vl")]
@@ -839,4 +968,23 @@ mod test {
         assert_eq!(splits[2], x2);
         assert_eq!(splits[3], x3);
     }
+
+    #[test]
+    fn sqrt_ratio_i_vs_serial() {
+        let a = FieldElement51([2438, 24, 243, 0, 0]).invert();
+        let b = FieldElement51([10000, 10001, 10002, 10003, 10004]);
+        let two = FieldElement51([2, 0, 0, 0, 0]);
+        let zero = FieldElement51::ZERO;
+
+        // Cover squares, nonsquares, and zero numerators and denominators.
+        let u = [a.square(), two, zero, a];
+        let v = [b, FieldElement51::ONE, b, zero];
+
+        let result = F51x4Reduced::sqrt_ratio_i(&u, &v);
+        for i in 0..4 {
+            let (expected_choice, expected_root) = FieldElement51::sqrt_ratio_i(&u[i], &v[i]);
+            assert_eq!(bool::from(result[i].0), bool::from(expected_choice));
+            assert_eq!(result[i].1, expected_root);
+        }
+    }
 }
@@ -9,12 +9,16 @@
 
 #![doc = include_str!("../../../../docs/ifma-notes.md")]
 
-#[allow(missing_docs)]
 pub mod field;
 
 #[allow(missing_docs)]
 pub mod edwards;
 
+pub mod montgomery;
+
+#[cfg(feature = "alloc")]
+pub mod compress;
+
 pub mod constants;
 
 pub(crate) use self::edwards::{CachedPoint, ExtendedPoint};
@@ -0,0 +1,129 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! A 4-way parallel Montgomery ladder.
+//!
+//! Each lane carries the state of an independent ladder, so four
+//! variable-base X25519 operations proceed in lockstep.  See the `avx2`
+//! version of this module for details.
+
+#![allow(non_snake_case)]
+
+use subtle::Choice;
+
+use curve25519_dalek_derive::unsafe_target_feature;
+
+use super::field::{F51x4Reduced, F51x4Unreduced};
+use crate::backend::serial::u64::field::FieldElement51;
+use crate::montgomery::MontgomeryPoint;
+use crate::scalar::Scalar;
+
+/// Given the \\(u\\)-coordinates \\(u\_i = u(P\_i)\\) of four points and
+/// four scalars \\(n\_i < 2\^{255}\\), compute \\(u(\[n\_i\]P\_i)\\).
+#[unsafe_target_feature("avx512ifma,avx512vl")]
+pub fn mul_x4(points: &[MontgomeryPoint; 4], scalars: &[Scalar; 4]) -> [MontgomeryPoint; 4] {
+    let u = points.map(|p| FieldElement51::from_bytes(&p.0));
+    let affine_u = F51x4Reduced::from(F51x4Unreduced::new(&u[0], &u[1], &u[2], &u[3]));
+
+    let one = &FieldElement51::ONE;
+    let zero = &FieldElement51::ZERO;
+
+    // (U0 : W0) starts at the identity, (U1 : W1) at the input point.
+    let mut U0 = F51x4Reduced::from(F51x4Unreduced::new(one, one, one, one));
+    let mut W0 = F51x4Reduced::from(F51x4Unreduced::new(zero, zero, zero, zero));
+    let mut U1 = affine_u;
+    let mut W1 = U0;
+
+    let bit = |n: &Scalar, i: usize| (n.bytes[i >> 3] >> (i & 7)) & 1;
+
+    // By scalar invariant #1, bit 255 is zero, so start at bit 254.
+    let mut prev_bits = [0u8; 4];
+    for i in (0..255).rev() {
+        let cur_bits = [
+            bit(&scalars[0], i),
+            bit(&scalars[1], i),
+            bit(&scalars[2], i),
+            bit(&scalars[3], i),
+        ];
+        let choices = [
+            Choice::from(prev_bits[0] ^ cur_bits[0]),
+            Choice::from(prev_bits[1] ^ cur_bits[1]),
+            Choice::from(prev_bits[2] ^ cur_bits[2]),
+            Choice::from(prev_bits[3] ^ cur_bits[3]),
+        ];
+        F51x4Reduced::conditional_swap_lanes(&mut U0, &mut U1, choices);
+        F51x4Reduced::conditional_swap_lanes(&mut W0, &mut W1, choices);
+        differential_add_and_double(&mut U0, &mut W0, &mut U1, &mut W1, &affine_u);
+        prev_bits = cur_bits;
+    }
+    let choices = prev_bits.map(Choice::from);
+    F51x4Reduced::conditional_swap_lanes(&mut U0, &mut U1, choices);
+    F51x4Reduced::conditional_swap_lanes(&mut W0, &mut W1, choices);
+    // Don't leave the bits on the stack
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(&mut prev_bits);
+
+    let U = F51x4Unreduced::from(U0).split();
+    let mut W = F51x4Unreduced::from(W0).split();
+    FieldElement51::batch_invert_array(&mut W);
+    [
+        MontgomeryPoint((&U[0] * &W[0]).as_bytes()),
+        MontgomeryPoint((&U[1] * &W[1]).as_bytes()),
+        MontgomeryPoint((&U[2] * &W[2]).as_bytes()),
+        MontgomeryPoint((&U[3] * &W[3]).as_bytes()),
+    ]
+}
+
+/// Perform the double-and-add step of the Montgomery ladder in each lane.
+///
+/// This follows `montgomery::differential_add_and_double`, reducing every
+/// intermediate value before it is used as a multiplicand.
+#[unsafe_target_feature("avx512ifma,avx512vl")]
+#[rustfmt::skip] // keep alignment of explanatory comments
+#[inline(always)]
+fn differential_add_and_double(
+    U_P: &mut F51x4Reduced,
+    W_P: &mut F51x4Reduced,
+    U_Q: &mut F51x4Reduced,
+    W_Q: &mut F51x4Reduced,
+    affine_PmQ: &F51x4Reduced,
+) {
+    let (u_p, w_p) = (F51x4Unreduced::from(*U_P), F51x4Unreduced::from(*W_P));
+    let (u_q, w_q) = (F51x4Unreduced::from(*U_Q), F51x4Unreduced::from(*W_Q));
+
+    let t0 = F51x4Reduced::from(u_p + w_p);
+    let t1 = F51x4Reduced::from(u_p + w_p.negate_lazy());
+    let t2 = F51x4Reduced::from(u_q + w_q);
+    let t3 = F51x4Reduced::from(u_q + w_q.negate_lazy());
+
+    let t4 = t0.square();
+    let t5 = t1.square();
+
+    let t6 = F51x4Reduced::from(t4 + t5.negate_lazy());
+
+    let t7 = &t0 * &t3;
+    let t8 = &t1 * &t2;
+
+    let t9  = F51x4Reduced::from(t7 + t8);
+    let t10 = F51x4Reduced::from(t7 + t8.negate_lazy());
+
+    let t11 = t9.square();
+    let t12 = F51x4Reduced::from(t10.square());
+
+    let t13 = &t6 * (121666, 121666, 121666, 121666);
+
+    let t14 = &F51x4Reduced::from(t4) * &F51x4Reduced::from(t5);
+    let t15 = F51x4Reduced::from(t13 + t5);
+
+    let t16 = &t6 * &t15;
+
+    let t17 = affine_PmQ * &t12;
+    let t18 = t11;
+
+    *U_P = F51x4Reduced::from(t14);
+    *W_P = F51x4Reduced::from(t16);
+    *U_Q = F51x4Reduced::from(t18);
+    *W_Q = F51x4Reduced::from(t17);
+}
@@ -16,7 +16,7 @@ pub mod packed_simd;
 
 pub mod avx2;
 
-#[cfg(nightly)]
+#[cfg(curve25519_dalek_avx512)]
 pub mod ifma;
 
 pub mod scalar_mul;
@@ -28,3 +28,7 @@ pub mod precomputed_straus;
 #[allow(missing_docs)]
 #[cfg(feature = "alloc")]
 pub mod pippenger;
+
+#[allow(missing_docs)]
+#[cfg(feature = "alloc")]
+pub mod recode;
@@ -11,7 +11,7 @@
 
 #[curve25519_dalek_derive::unsafe_target_feature_specialize(
     "avx2",
-    conditional("avx512ifma,avx512vl", nightly)
+    conditional("avx512ifma,avx512vl", curve25519_dalek_avx512)
 )]
 pub mod spec {
 
@@ -26,6 +26,12 @@ pub mod spec {
     #[for_target_feature("avx512ifma")]
     use crate::backend::vector::ifma::{CachedPoint, ExtendedPoint};
 
+    #[for_target_feature("avx2")]
+    use crate::backend::vector::scalar_mul::recode::spec_avx2::as_radix_2w_batch;
+
+    #[for_target_feature("avx512ifma")]
+    use crate::backend::vector::scalar_mul::recode::spec_avx512ifma_avx512vl::as_radix_2w_batch;
+
     use crate::edwards::EdwardsPoint;
     use crate::scalar::Scalar;
     use crate::traits::{Identity, VartimeMultiscalarMul};
@@ -60,7 +66,7 @@ pub mod spec {
 
             // Collect optimized scalars and points in a buffer for repeated access
             // (scanning the whole collection per each digit position).
-            let scalars = scalars.map(|s| s.borrow().as_radix_2w(w));
+            let scalars = as_radix_2w_batch(scalars, w).into_iter();
 
             let points = points
                 .into_iter()
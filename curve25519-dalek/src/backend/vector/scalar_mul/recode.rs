@@ -0,0 +1,162 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Batch recoding of scalars into signed radix-\\(2\^w\\) digits.
+//!
+//! Multiscalar multiplication starts by recoding every input scalar.  Here
+//! the recoding is done four scalars at a time, one scalar per 64-bit
+//! lane: the digit windows sit at the same bit offsets in every scalar,
+//! so each step of `Scalar::as_radix_2w` becomes a single vector
+//! operation.  There are no data-dependent branches, so the recoding is
+//! constant-time as for the serial version.
+
+#![allow(non_snake_case)]
+
+#[curve25519_dalek_derive::unsafe_target_feature_specialize(
+    "avx2",
+    conditional("avx512ifma,avx512vl", curve25519_dalek_avx512)
+)]
+pub mod spec {
+
+    use alloc::vec::Vec;
+
+    use core::arch::x86_64::*;
+    use core::borrow::Borrow;
+
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    use crate::scalar::Scalar;
+
+    /// Compute `s.as_radix_2w(w)` for each scalar `s` in `scalars`, for
+    /// \\(4 \leq w \leq 8\\).
+    pub fn as_radix_2w_batch<I>(scalars: I, w: usize) -> Vec<[i8; 64]>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+    {
+        let mut scalars = scalars.into_iter();
+        let mut digits = Vec::with_capacity(scalars.size_hint().0);
+        loop {
+            // Fill up to four lanes; unused lanes recode zero.
+            let mut chunk = [Scalar::ZERO; 4];
+            let mut n = 0;
+            for (lane, s) in chunk.iter_mut().zip(scalars.by_ref()) {
+                *lane = *s.borrow();
+                n += 1;
+            }
+            if n > 0 {
+                digits.extend_from_slice(&as_radix_2w_x4(&chunk, w)[..n]);
+            }
+            #[cfg(feature = "zeroize")]
+            chunk.zeroize();
+            if n < 4 {
+                return digits;
+            }
+        }
+    }
+
+    /// Compute `s.as_radix_2w(w)` for each of four scalars.
+    #[allow(clippy::needless_range_loop)]
+    fn as_radix_2w_x4(scalars: &[Scalar; 4], w: usize) -> [[i8; 64]; 4] {
+        debug_assert!(w >= 4);
+        debug_assert!(w <= 8);
+
+        // limbs[j] holds the j-th 64-bit limb of each scalar.
+        let mut limbs = [[0u64; 4]; 4];
+        for (k, s) in scalars.iter().enumerate() {
+            for (j, limb) in limbs.iter_mut().enumerate() {
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(&s.as_bytes()[8 * j..8 * j + 8]);
+                limb[k] = u64::from_le_bytes(buf);
+            }
+        }
+
+        let mut digits = [[0i8; 64]; 4];
+        let digits_count = (256 + w - 1) / w;
+        let mut lanes = [0u64; 4];
+
+        unsafe {
+            let limbs = [
+                _mm256_loadu_si256(limbs[0].as_ptr() as *const __m256i),
+                _mm256_loadu_si256(limbs[1].as_ptr() as *const __m256i),
+                _mm256_loadu_si256(limbs[2].as_ptr() as *const __m256i),
+                _mm256_loadu_si256(limbs[3].as_ptr() as *const __m256i),
+            ];
+            let radix = 1u64 << w;
+            let window_mask = _mm256_set1_epi64x((radix - 1) as i64);
+            let half_radix = _mm256_set1_epi64x((radix / 2) as i64);
+            let w_count = _mm_cvtsi64_si128(w as i64);
+
+            let mut carry = _mm256_setzero_si256();
+            for i in 0..digits_count {
+                // Construct a buffer of bits of each scalar, starting at `bit_offset`.
+                let bit_offset = i * w;
+                let u64_idx = bit_offset / 64;
+                let bit_idx = bit_offset % 64;
+
+                let mut bit_buf =
+                    _mm256_srl_epi64(limbs[u64_idx], _mm_cvtsi64_si128(bit_idx as i64));
+                if !(bit_idx < 64 - w || u64_idx == 3) {
+                    // Combine the current u64's bits with the bits from the next u64
+                    let hi = _mm256_sll_epi64(
+                        limbs[u64_idx + 1],
+                        _mm_cvtsi64_si128((64 - bit_idx) as i64),
+                    );
+                    bit_buf = _mm256_or_si256(bit_buf, hi);
+                }
+
+                // coef = [0, 2^w), then recenter to [-2^w/2, 2^w/2)
+                let coef = _mm256_add_epi64(carry, _mm256_and_si256(bit_buf, window_mask));
+                carry = _mm256_srl_epi64(_mm256_add_epi64(coef, half_radix), w_count);
+                let digit = _mm256_sub_epi64(coef, _mm256_sll_epi64(carry, w_count));
+
+                _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, digit);
+                for k in 0..4 {
+                    digits[k][i] = lanes[k] as i64 as i8;
+                }
+            }
+
+            _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, carry);
+        }
+
+        // Fold the final carry as `Scalar::as_radix_2w` does.
+        for k in 0..4 {
+            match w {
+                8 => digits[k][digits_count] += lanes[k] as i8,
+                _ => digits[k][digits_count - 1] += (lanes[k] << w) as i8,
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        {
+            limbs.zeroize();
+            lanes.zeroize();
+        }
+
+        digits
+    }
+
+    #[cfg(test)]
+    mod test {
+        #[test]
+        fn as_radix_2w_batch_matches_serial() {
+            use super::*;
+
+            let mut rng = rand::thread_rng();
+            let mut scalars: Vec<Scalar> = (0..11).map(|_| Scalar::random(&mut rng)).collect();
+            scalars.push(Scalar::ZERO);
+            scalars.push(-Scalar::ONE);
+            scalars.push(Scalar::from(u64::MAX));
+
+            for w in 4..=8 {
+                for n in 0..=scalars.len() {
+                    let expected: Vec<_> = scalars[..n].iter().map(|s| s.as_radix_2w(w)).collect();
+                    assert_eq!(as_radix_2w_batch(&scalars[..n], w), expected);
+                }
+            }
+        }
+    }
+}
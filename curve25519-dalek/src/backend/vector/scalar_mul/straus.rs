@@ -13,7 +13,7 @@
 
 #[curve25519_dalek_derive::unsafe_target_feature_specialize(
     "avx2",
-    conditional("avx512ifma,avx512vl", nightly)
+    conditional("avx512ifma,avx512vl", curve25519_dalek_avx512)
 )]
 pub mod spec {
 
@@ -31,6 +31,12 @@ pub mod spec {
     #[for_target_feature("avx512ifma")]
     use crate::backend::vector::ifma::{CachedPoint, ExtendedPoint};
 
+    #[for_target_feature("avx2")]
+    use crate::backend::vector::scalar_mul::recode::spec_avx2::as_radix_2w_batch;
+
+    #[for_target_feature("avx512ifma")]
+    use crate::backend::vector::scalar_mul::recode::spec_avx512ifma_avx512vl::as_radix_2w_batch;
+
     use crate::edwards::EdwardsPoint;
     use crate::scalar::Scalar;
     use crate::traits::{Identity, MultiscalarMul, VartimeMultiscalarMul};
@@ -63,10 +69,7 @@ pub mod spec {
                 .map(|point| LookupTable::<CachedPoint>::from(point.borrow()))
                 .collect();
 
-            let scalar_digits_vec: Vec<_> = scalars
-                .into_iter()
-                .map(|s| s.borrow().as_radix_16())
-                .collect();
+            let scalar_digits_vec = as_radix_2w_batch(scalars, 4);
             // Pass ownership to a `Zeroizing` wrapper
             #[cfg(feature = "zeroize")]
             let scalar_digits_vec = Zeroizing::new(scalar_digits_vec);
@@ -2,7 +2,7 @@
 
 #[curve25519_dalek_derive::unsafe_target_feature_specialize(
     "avx2",
-    conditional("avx512ifma,avx512vl", nightly)
+    conditional("avx512ifma,avx512vl", curve25519_dalek_avx512)
 )]
 pub mod spec {
 
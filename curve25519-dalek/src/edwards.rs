@@ -101,6 +101,9 @@ use core::ops::{Add, Neg, Sub};
 use core::ops::{AddAssign, SubAssign};
 use core::ops::{Mul, MulAssign};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use cfg_if::cfg_if;
 
 #[cfg(feature = "digest")]
@@ -124,12 +127,16 @@ use subtle::ConstantTimeEq;
 use zeroize::Zeroize;
 
 use crate::constants;
+use crate::encoding::ParseHexError;
 
 use crate::field::FieldElement;
 use crate::scalar::{clamp_integer, Scalar};
 
 use crate::montgomery::MontgomeryPoint;
 
+#[cfg(feature = "hazmat")]
+use crate::backend::Backend;
+
 use crate::backend::serial::curve_models::AffineNielsPoint;
 use crate::backend::serial::curve_models::CompletedPoint;
 use crate::backend::serial::curve_models::ProjectiveNielsPoint;
@@ -150,7 +157,9 @@ use crate::traits::{Identity, IsIdentity};
 #[cfg(feature = "alloc")]
 use crate::traits::MultiscalarMul;
 #[cfg(feature = "alloc")]
-use crate::traits::{VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul};
+use crate::traits::{
+    MultiscalarMulAlgorithm, VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul,
+};
 
 // ------------------------------------------------------------------------
 // Compressed points
@@ -162,6 +171,7 @@ use crate::traits::{VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul};
 /// The first 255 bits of a `CompressedEdwardsY` represent the
 /// \\(y\\)-coordinate.  The high bit of the 32nd byte gives the sign of \\(x\\).
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
 pub struct CompressedEdwardsY(pub [u8; 32]);
 
 impl ConstantTimeEq for CompressedEdwardsY {
@@ -170,13 +180,88 @@ impl ConstantTimeEq for CompressedEdwardsY {
     }
 }
 
+/// Compares encodings byte-lexicographically, in variable time.
+///
+/// This is meant for sorting and deduplicating public values, e.g. keys in
+/// a `BTreeMap`; it must not be used on secret data.  The order has no
+/// meaning in the group.
+impl PartialOrd for CompressedEdwardsY {
+    fn partial_cmp(&self, other: &CompressedEdwardsY) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// See the `PartialOrd` implementation: this is variable-time.
+impl Ord for CompressedEdwardsY {
+    fn cmp(&self, other: &CompressedEdwardsY) -> core::cmp::Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
 impl Debug for CompressedEdwardsY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "CompressedEdwardsY: {:?}", self.as_bytes())
     }
 }
 
+impl core::fmt::Display for CompressedEdwardsY {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::encoding::fmt_hex(self.as_bytes(), f)
+    }
+}
+
+impl core::fmt::LowerHex for CompressedEdwardsY {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::encoding::fmt_hex(self.as_bytes(), f)
+    }
+}
+
+impl core::str::FromStr for CompressedEdwardsY {
+    type Err = ParseHexError;
+
+    /// Parse 64 hex digits, accepting only the canonical encoding of a
+    /// point on the curve.
+    fn from_str(s: &str) -> Result<CompressedEdwardsY, ParseHexError> {
+        let compressed = CompressedEdwardsY(crate::encoding::decode_hex(s)?);
+        // Decompression accepts some non-canonical encodings, which don't
+        // survive the round trip.
+        match compressed.decompress() {
+            Some(P) if P.compress() == compressed => Ok(compressed),
+            _ => Err(ParseHexError::InvalidEncoding),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CompressedEdwardsY {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "CompressedEdwardsY({=u64:016x}...)",
+            crate::encoding::short_hex(self.as_bytes())
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for EdwardsPoint {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "EdwardsPoint({=u64:016x}...)",
+            crate::encoding::short_hex(self.compress().as_bytes())
+        )
+    }
+}
+
 impl CompressedEdwardsY {
+    /// Encode the point with affine coordinates \\((x, y)\\).
+    pub(crate) fn from_affine(x: &FieldElement, y: &FieldElement) -> CompressedEdwardsY {
+        let mut s = y.as_bytes();
+        s[31] ^= x.is_negative().unwrap_u8() << 7;
+        CompressedEdwardsY(s)
+    }
+
     /// View this `CompressedEdwardsY` as an array of bytes.
     pub const fn as_bytes(&self) -> &[u8; 32] {
         &self.0
@@ -200,11 +285,63 @@ impl CompressedEdwardsY {
             None
         }
     }
+
+    /// Attempt to decompress four points at once.
+    ///
+    /// This returns the same results as calling
+    /// [`decompress`](CompressedEdwardsY::decompress) on each point, but on
+    /// CPUs with AVX2 or AVX512 IFMA the square root computations for all
+    /// four points run in parallel vector lanes.
+    pub fn decompress_x4(compressed: &[CompressedEdwardsY; 4]) -> [Option<EdwardsPoint>; 4] {
+        let steps = decompress::step_1_x4(compressed);
+        [0, 1, 2, 3].map(|i| {
+            let (is_valid_y_coord, X, Y, Z) = steps[i];
+            if is_valid_y_coord.into() {
+                Some(decompress::step_2(&compressed[i], X, Y, Z))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Attempt to decompress a slice of points.
+    ///
+    /// This returns the same results as calling
+    /// [`decompress`](CompressedEdwardsY::decompress) on each point, but
+    /// processes them four at a time with
+    /// [`decompress_x4`](CompressedEdwardsY::decompress_x4).
+    #[cfg(feature = "alloc")]
+    pub fn decompress_batch(compressed: &[CompressedEdwardsY]) -> Vec<Option<EdwardsPoint>> {
+        let mut points = Vec::with_capacity(compressed.len());
+        let mut chunks = compressed.chunks_exact(4);
+        for chunk in &mut chunks {
+            let chunk = [chunk[0], chunk[1], chunk[2], chunk[3]];
+            points.extend_from_slice(&CompressedEdwardsY::decompress_x4(&chunk));
+        }
+        points.extend(chunks.remainder().iter().map(|c| c.decompress()));
+        points
+    }
 }
 
 mod decompress {
     use super::*;
 
+    /// Perform `step_1` for four points, with the square roots computed
+    /// by the selected backend.
+    #[allow(clippy::type_complexity)]
+    pub(super) fn step_1_x4(
+        reprs: &[CompressedEdwardsY; 4],
+    ) -> [(Choice, FieldElement, FieldElement, FieldElement); 4] {
+        let Y = reprs.map(|repr| FieldElement::from_bytes(repr.as_bytes()));
+        let Z = FieldElement::ONE;
+        let YY = Y.map(|y| y.square());
+        let u = YY.map(|yy| &yy - &Z); // u =  y²-1
+        let v = YY.map(|yy| &(&yy * &constants::EDWARDS_D) + &Z); // v = dy²+1
+        let roots = crate::backend::sqrt_ratio_i_x4(&u, &v);
+
+        [0, 1, 2, 3].map(|i| (roots[i].0, roots[i].1, Y[i], Z))
+    }
+
     #[rustfmt::skip] // keep alignment of explanatory comments
     pub(super) fn step_1(
         repr: &CompressedEdwardsY,
@@ -255,9 +392,10 @@ impl TryFrom<&[u8]> for CompressedEdwardsY {
 // and decompression internally.  This means that users can create
 // structs containing `EdwardsPoint`s and use Serde's derived
 // serializers to serialize those structures.
+//
+// Human-readable formats get the encoding as a hex string; see the
+// `encoding` module.
 
-#[cfg(feature = "serde")]
-use serde::de::Visitor;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -267,12 +405,7 @@ impl Serialize for EdwardsPoint {
     where
         S: Serializer,
     {
-        use serde::ser::SerializeTuple;
-        let mut tup = serializer.serialize_tuple(32)?;
-        for byte in self.compress().as_bytes().iter() {
-            tup.serialize_element(byte)?;
-        }
-        tup.end()
+        crate::encoding::serialize_bytes(self.compress().as_bytes(), serializer)
     }
 }
 
@@ -282,12 +415,7 @@ impl Serialize for CompressedEdwardsY {
     where
         S: Serializer,
     {
-        use serde::ser::SerializeTuple;
-        let mut tup = serializer.serialize_tuple(32)?;
-        for byte in self.as_bytes().iter() {
-            tup.serialize_element(byte)?;
-        }
-        tup.end()
+        crate::encoding::serialize_bytes(self.as_bytes(), serializer)
     }
 }
 
@@ -297,33 +425,10 @@ impl<'de> Deserialize<'de> for EdwardsPoint {
     where
         D: Deserializer<'de>,
     {
-        struct EdwardsPointVisitor;
-
-        impl<'de> Visitor<'de> for EdwardsPointVisitor {
-            type Value = EdwardsPoint;
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str("a valid point in Edwards y + sign format")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<EdwardsPoint, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>,
-            {
-                let mut bytes = [0u8; 32];
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..32 {
-                    bytes[i] = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
-                }
-                CompressedEdwardsY(bytes)
-                    .decompress()
-                    .ok_or_else(|| serde::de::Error::custom("decompression failed"))
-            }
-        }
-
-        deserializer.deserialize_tuple(32, EdwardsPointVisitor)
+        let bytes = crate::encoding::deserialize_bytes(deserializer)?;
+        CompressedEdwardsY(bytes)
+            .decompress()
+            .ok_or_else(|| serde::de::Error::custom("decompression failed"))
     }
 }
 
@@ -333,31 +438,8 @@ impl<'de> Deserialize<'de> for CompressedEdwardsY {
     where
         D: Deserializer<'de>,
     {
-        struct CompressedEdwardsYVisitor;
-
-        impl<'de> Visitor<'de> for CompressedEdwardsYVisitor {
-            type Value = CompressedEdwardsY;
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str("32 bytes of data")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<CompressedEdwardsY, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>,
-            {
-                let mut bytes = [0u8; 32];
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..32 {
-                    bytes[i] = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
-                }
-                Ok(CompressedEdwardsY(bytes))
-            }
-        }
-
-        deserializer.deserialize_tuple(32, CompressedEdwardsYVisitor)
+        let bytes = crate::encoding::deserialize_bytes(deserializer)?;
+        Ok(CompressedEdwardsY(bytes))
     }
 }
 
@@ -394,6 +476,15 @@ impl Default for CompressedEdwardsY {
     }
 }
 
+// SAFETY: `CompressedEdwardsY` is a `#[repr(transparent)]` `[u8; 32]`, and
+// any 32 bytes are a valid (if not necessarily decompressible) value.  Note
+// that `Zeroable::zeroed()` is not the identity, whose encoding starts with 1.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for CompressedEdwardsY {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for CompressedEdwardsY {}
+
 impl CompressedEdwardsY {
     /// Construct a `CompressedEdwardsY` from a slice of bytes.
     ///
@@ -567,11 +658,21 @@ impl EdwardsPoint {
         let recip = self.Z.invert();
         let x = &self.X * &recip;
         let y = &self.Y * &recip;
-        let mut s: [u8; 32];
+        CompressedEdwardsY::from_affine(&x, &y)
+    }
 
-        s = y.as_bytes();
-        s[31] ^= x.is_negative().unwrap_u8() << 7;
-        CompressedEdwardsY(s)
+    /// Compress a batch of points to `CompressedEdwardsY` format.
+    ///
+    /// This is equivalent to calling [`EdwardsPoint::compress`] on each
+    /// point, but shares a single field inversion between all of them, and
+    /// on CPUs with AVX2 or AVX512 IFMA processes four points at a time.
+    #[cfg(feature = "alloc")]
+    pub fn compress_batch<'a, I>(points: I) -> Vec<CompressedEdwardsY>
+    where
+        I: IntoIterator<Item = &'a EdwardsPoint>,
+    {
+        let points: Vec<&EdwardsPoint> = points.into_iter().collect();
+        crate::backend::compress_batch(&points)
     }
 
     #[cfg(feature = "digest")]
@@ -852,14 +953,98 @@ impl VartimeMultiscalarMul for EdwardsPoint {
         // Use this as the hint to decide which algorithm to use.
         let size = s_lo;
 
-        if size < 190 {
-            crate::backend::straus_optional_multiscalar_mul(scalars, points)
+        let algorithm = if size < 190 {
+            MultiscalarMulAlgorithm::Straus
         } else {
-            crate::backend::pippenger_optional_multiscalar_mul(scalars, points)
+            MultiscalarMulAlgorithm::Pippenger
+        };
+        EdwardsPoint::optional_multiscalar_mul_with_algorithm(algorithm, scalars, points)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl EdwardsPoint {
+    /// Compute \\(\sum\_i s\_i P\_i\\) in variable time with the given
+    /// multiscalar multiplication `algorithm`.
+    ///
+    /// This is [`VartimeMultiscalarMul::vartime_multiscalar_mul`] without
+    /// the size-based choice of algorithm; the result is the same for
+    /// every algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    pub fn vartime_multiscalar_mul_with_algorithm<I, J>(
+        algorithm: MultiscalarMulAlgorithm,
+        scalars: I,
+        points: J,
+    ) -> EdwardsPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<EdwardsPoint>,
+    {
+        let scalars: Vec<Scalar> = scalars.into_iter().map(|s| *s.borrow()).collect();
+        let points: Vec<Option<EdwardsPoint>> =
+            points.into_iter().map(|P| Some(*P.borrow())).collect();
+        assert_eq!(scalars.len(), points.len());
+
+        EdwardsPoint::optional_multiscalar_mul_with_algorithm(algorithm, scalars, points)
+            .expect("should return some point")
+    }
+
+    pub(crate) fn optional_multiscalar_mul_with_algorithm<I, J>(
+        algorithm: MultiscalarMulAlgorithm,
+        scalars: I,
+        points: J,
+    ) -> Option<EdwardsPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<EdwardsPoint>>,
+    {
+        match algorithm {
+            MultiscalarMulAlgorithm::Auto => {
+                EdwardsPoint::optional_multiscalar_mul(scalars, points)
+            }
+            MultiscalarMulAlgorithm::Straus => {
+                crate::backend::straus_optional_multiscalar_mul(scalars, points)
+            }
+            MultiscalarMulAlgorithm::Pippenger => {
+                crate::backend::pippenger_optional_multiscalar_mul(scalars, points)
+            }
         }
     }
 }
 
+#[cfg(feature = "rayon")]
+impl EdwardsPoint {
+    /// Compute \\(\sum\_i s\_i P\_i\\) in variable time, spreading the
+    /// work across the `rayon` thread pool.
+    ///
+    /// This runs Pippenger's algorithm with the bucket accumulation for
+    /// each window of digits as an independent job.  The windows are
+    /// combined in a fixed order, so the result does not depend on
+    /// scheduling.  It is intended for large inputs; for small ones the
+    /// threading overhead outweighs the gain and
+    /// [`VartimeMultiscalarMul::vartime_multiscalar_mul`] is faster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    pub fn par_vartime_multiscalar_mul(
+        scalars: &[Scalar],
+        points: &[EdwardsPoint],
+    ) -> EdwardsPoint {
+        use crate::backend::serial::scalar_mul::pippenger::Pippenger;
+
+        assert_eq!(scalars.len(), points.len());
+        Pippenger::par_optional_multiscalar_mul(scalars, points.iter().map(|P| Some(*P)))
+            .expect("should return some point")
+    }
+}
+
 /// Precomputation for variable-time multiscalar multiplication with `EdwardsPoint`s.
 // This wraps the inner implementation in a facade type so that we can
 // decouple stability of the inner type from the stability of the
@@ -908,6 +1093,63 @@ impl EdwardsPoint {
     }
 }
 
+#[cfg(feature = "hazmat")]
+impl EdwardsPoint {
+    /// Multiply this point by `scalar` using `backend`, instead of the
+    /// backend selected for the current CPU.
+    ///
+    /// Returns `None` if `backend` is not available on this CPU or in this
+    /// build; see [`Backend::is_available`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants::ED25519_BASEPOINT_POINT;
+    /// use curve25519_dalek::scalar::Scalar;
+    /// use curve25519_dalek::Backend;
+    ///
+    /// let x = Scalar::from(1234u64);
+    /// let P = ED25519_BASEPOINT_POINT.mul_with(Backend::Serial, &x);
+    /// assert_eq!(P, Some(ED25519_BASEPOINT_POINT * x));
+    /// ```
+    pub fn mul_with(&self, backend: Backend, scalar: &Scalar) -> Option<EdwardsPoint> {
+        crate::backend::variable_base_mul_with(backend, self, scalar)
+    }
+
+    /// Compute \\(aA + bB\\) in variable time using `backend`, where
+    /// \\(B\\) is the Ed25519 basepoint.
+    ///
+    /// Returns `None` if `backend` is not available on this CPU or in this
+    /// build.
+    pub fn vartime_double_scalar_mul_basepoint_with(
+        backend: Backend,
+        a: &Scalar,
+        A: &EdwardsPoint,
+        b: &Scalar,
+    ) -> Option<EdwardsPoint> {
+        crate::backend::vartime_double_base_mul_with(backend, a, A, b)
+    }
+
+    /// Compute the constant-time multiscalar multiplication
+    /// \\(\sum\_i s\_i P\_i\\) using `backend`.
+    ///
+    /// Returns `None` if `backend` is not available on this CPU or in this
+    /// build.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    #[cfg(feature = "alloc")]
+    pub fn multiscalar_mul_with(
+        backend: Backend,
+        scalars: &[Scalar],
+        points: &[EdwardsPoint],
+    ) -> Option<EdwardsPoint> {
+        assert_eq!(scalars.len(), points.len());
+        crate::backend::straus_multiscalar_mul_with(backend, scalars, points)
+    }
+}
+
 #[cfg(feature = "precomputed-tables")]
 macro_rules! impl_basepoint_table {
     (Name = $name:ident, LookupTable = $table:ident, Point = $point:ty, Radix = $radix:expr, Additions = $adds:expr) => {
@@ -1602,6 +1844,7 @@ mod test {
 
     #[cfg(feature = "precomputed-tables")]
     use crate::constants::ED25519_BASEPOINT_TABLE;
+    use crate::ristretto::{CompressedRistretto, RistrettoPoint};
 
     /// X coordinate of the basepoint.
     /// = 15112221349535400772501151409588531511454012693041857206046113283949847762202
@@ -1688,6 +1931,105 @@ mod test {
         assert_eq!(minus_basepoint.T, -(&constants::ED25519_BASEPOINT_POINT.T));
     }
 
+    /// Test that batch decompression agrees with `decompress`, including
+    /// on invalid encodings.
+    #[test]
+    fn decompress_x4_matches_decompress() {
+        let mut rng = rand::thread_rng();
+        let mut compressed = [CompressedEdwardsY::identity(); 17];
+        compressed[1] = constants::ED25519_BASEPOINT_COMPRESSED;
+        compressed[2] = CompressedEdwardsY([0u8; 32]);
+        for c in compressed[4..].iter_mut() {
+            rng.fill_bytes(&mut c.0);
+        }
+        // y = 2, 7 and 8 are not the y-coordinate of any point.  Place
+        // them in the first chunk, a later chunk and the remainder.
+        for (i, y) in [(3, 2), (9, 7), (16, 8)] {
+            compressed[i] = CompressedEdwardsY([0u8; 32]);
+            compressed[i].0[0] = y;
+        }
+        let expected = compressed.map(|c| c.decompress());
+        assert!(expected[1].is_some());
+        assert!(expected[3].is_none() && expected[9].is_none() && expected[16].is_none());
+
+        for i in 0..4 {
+            let chunk = [0, 1, 2, 3].map(|j| compressed[4 * i + j]);
+            let points = CompressedEdwardsY::decompress_x4(&chunk);
+            assert_eq!(points[..], expected[4 * i..4 * i + 4]);
+        }
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            CompressedEdwardsY::decompress_batch(&compressed),
+            expected[..]
+        );
+    }
+
+    /// Test that each available backend can be selected explicitly.
+    #[test]
+    #[cfg(feature = "hazmat")]
+    fn backend_override() {
+        let mut rng = rand::thread_rng();
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+        let A = constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng);
+
+        assert!(Backend::Serial.is_available());
+        for backend in [Backend::Serial, Backend::Avx2, Backend::Avx512Ifma] {
+            let expected = |P: EdwardsPoint| Some(P).filter(|_| backend.is_available());
+            assert_eq!(A.mul_with(backend, &a), expected(A * a));
+            assert_eq!(
+                EdwardsPoint::vartime_double_scalar_mul_basepoint_with(backend, &a, &A, &b),
+                expected(EdwardsPoint::vartime_double_scalar_mul_basepoint(
+                    &a, &A, &b
+                ))
+            );
+            #[cfg(feature = "alloc")]
+            assert_eq!(
+                EdwardsPoint::multiscalar_mul_with(backend, &[a, b], &[A, A]),
+                expected(A * (a + b))
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn multiscalar_mul_algorithms_agree() {
+        let mut rng = rand::thread_rng();
+        for n in [0, 1, 17, 200] {
+            let scalars: Vec<Scalar> = (0..n).map(|_| Scalar::random(&mut rng)).collect();
+            let points: Vec<EdwardsPoint> = (0..n)
+                .map(|_| constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng))
+                .collect();
+            let expected = EdwardsPoint::vartime_multiscalar_mul(&scalars, &points);
+            for algorithm in [
+                MultiscalarMulAlgorithm::Auto,
+                MultiscalarMulAlgorithm::Straus,
+                MultiscalarMulAlgorithm::Pippenger,
+            ] {
+                assert_eq!(
+                    EdwardsPoint::vartime_multiscalar_mul_with_algorithm(
+                        algorithm, &scalars, &points
+                    ),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn compress_batch_matches_compress() {
+        let mut rng = rand::thread_rng();
+        for n in [0, 1, 4, 7, 32] {
+            let points: Vec<EdwardsPoint> = (0..n)
+                .map(|_| constants::ED25519_BASEPOINT_POINT * Scalar::random(&mut rng))
+                .collect();
+            let expected: Vec<CompressedEdwardsY> = points.iter().map(|P| P.compress()).collect();
+            assert_eq!(EdwardsPoint::compress_batch(&points), expected);
+        }
+    }
+
     /// Test that computing 1*basepoint gives the correct basepoint.
     #[cfg(feature = "precomputed-tables")]
     #[test]
@@ -1994,8 +2336,37 @@ mod test {
 
     #[test]
     fn is_identity() {
+        use crate::traits::CtIsIdentity;
+
         assert!(EdwardsPoint::identity().is_identity());
         assert!(!constants::ED25519_BASEPOINT_POINT.is_identity());
+
+        assert!(bool::from(EdwardsPoint::identity().ct_is_identity()));
+        assert!(bool::from(CompressedEdwardsY::identity().ct_is_identity()));
+        assert!(bool::from(MontgomeryPoint::identity().ct_is_identity()));
+        assert!(bool::from(RistrettoPoint::identity().ct_is_identity()));
+        assert!(bool::from(CompressedRistretto::identity().ct_is_identity()));
+        assert!(!bool::from(
+            constants::ED25519_BASEPOINT_POINT.ct_is_identity()
+        ));
+        assert!(!bool::from(
+            constants::ED25519_BASEPOINT_COMPRESSED.ct_is_identity()
+        ));
+        assert!(!bool::from(constants::X25519_BASEPOINT.ct_is_identity()));
+        assert!(!bool::from(
+            constants::RISTRETTO_BASEPOINT_POINT.ct_is_identity()
+        ));
+        assert!(!bool::from(
+            constants::RISTRETTO_BASEPOINT_COMPRESSED.ct_is_identity()
+        ));
+
+        // The 4-torsion point with y = 0 is not the identity, though it is
+        // small.
+        let torsion = constants::EIGHT_TORSION[2];
+        assert!(!bool::from(torsion.ct_is_identity()));
+        assert!(bool::from(
+            (torsion + torsion + torsion + torsion).ct_is_identity()
+        ));
     }
 
     /// Rust's debug builds have overflow and underflow trapping,
@@ -0,0 +1,352 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Hexadecimal text encodings of points.
+//!
+//! `CompressedEdwardsY`, `CompressedRistretto` and `MontgomeryPoint`
+//! implement [`Display`](core::fmt::Display) and
+//! [`LowerHex`](core::fmt::LowerHex), which write the 32-byte encoding as
+//! 64 lowercase hex digits, and [`FromStr`](core::str::FromStr), which
+//! parses them back.  Parsing is strict: the input must be exactly 64 hex
+//! digits, with no prefix or whitespace, and must be the canonical
+//! encoding of a point.
+//!
+//! With the `serde` feature, the same encoding is used for points and
+//! scalars by human-readable formats such as JSON, while binary formats
+//! keep the compact 32-byte representation.
+//!
+//! ```
+//! use curve25519_dalek::constants::RISTRETTO_BASEPOINT_COMPRESSED;
+//! use curve25519_dalek::ristretto::CompressedRistretto;
+//!
+//! let text = RISTRETTO_BASEPOINT_COMPRESSED.to_string();
+//! assert_eq!(
+//!     text,
+//!     "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76"
+//! );
+//! assert_eq!(text.parse::<CompressedRistretto>(), Ok(RISTRETTO_BASEPOINT_COMPRESSED));
+//! ```
+
+use core::fmt;
+
+/// An error parsing the hex encoding of a point.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ParseHexError {
+    /// The input is not exactly 64 characters long.
+    InvalidLength,
+    /// The input contains a character which is not a hex digit.
+    InvalidCharacter,
+    /// The bytes are not the canonical encoding of a point.
+    InvalidEncoding,
+}
+
+impl fmt::Display for ParseHexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseHexError::InvalidLength => write!(f, "expected 64 hex digits"),
+            ParseHexError::InvalidCharacter => write!(f, "invalid hex digit"),
+            ParseHexError::InvalidEncoding => write!(f, "not a canonical point encoding"),
+        }
+    }
+}
+
+/// Encode `bytes` as 64 lowercase hex digits.
+///
+/// This doesn't branch on or index by the data, so it can be used on
+/// secret scalars.
+fn encode_hex(bytes: &[u8; 32]) -> [u8; 64] {
+    fn encode_nibble(n: u8) -> u8 {
+        let n = n as i16;
+        // Add 0x27 to skip from '9' + 1 to 'a' when n > 9.
+        (n + 0x30 + (((9 - n) >> 8) & 0x27)) as u8
+    }
+
+    let mut hex = [0u8; 64];
+    for (pair, b) in hex.chunks_exact_mut(2).zip(bytes) {
+        pair[0] = encode_nibble(b >> 4);
+        pair[1] = encode_nibble(b & 0xf);
+    }
+    hex
+}
+
+/// Write `bytes` as lowercase hex, with a `0x` prefix for `{:#x}`.
+pub(crate) fn fmt_hex(bytes: &[u8; 32], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if f.alternate() {
+        f.write_str("0x")?;
+    }
+    let hex = encode_hex(bytes);
+    f.write_str(core::str::from_utf8(&hex).map_err(|_| fmt::Error)?)
+}
+
+/// Return the first 8 bytes of `bytes` as a big-endian integer, so that
+/// `defmt` prints them as the first 16 hex digits of the encoding.
+#[cfg(feature = "defmt")]
+pub(crate) fn short_hex(bytes: &[u8; 32]) -> u64 {
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&bytes[..8]);
+    u64::from_be_bytes(prefix)
+}
+
+/// Decode exactly 64 hex digits, in either case, to 32 bytes.
+///
+/// Like `encode_hex`, this doesn't branch on the digits themselves.
+pub(crate) fn decode_hex(s: &str) -> Result<[u8; 32], ParseHexError> {
+    /// Return the value of the hex digit `c`, or a value with some of the
+    /// high 8 bits set if `c` is not a hex digit.
+    fn decode_nibble(c: u8) -> u16 {
+        let c = c as i16;
+        let mut n: i16 = -1;
+        // Each term is c - 0x2f etc. masked to the range it applies to.
+        n += (((0x2f - c) & (c - 0x3a)) >> 8) & (c - 0x2f); // 0-9
+        n += (((0x40 - c) & (c - 0x47)) >> 8) & (c - 0x36); // A-F
+        n += (((0x60 - c) & (c - 0x67)) >> 8) & (c - 0x56); // a-f
+        n as u16
+    }
+
+    let s = s.as_bytes();
+    if s.len() != 64 {
+        return Err(ParseHexError::InvalidLength);
+    }
+    let mut bytes = [0u8; 32];
+    let mut invalid = 0u16;
+    for (b, pair) in bytes.iter_mut().zip(s.chunks_exact(2)) {
+        let (hi, lo) = (decode_nibble(pair[0]), decode_nibble(pair[1]));
+        invalid |= (hi | lo) >> 8;
+        *b = ((hi << 4) | lo) as u8;
+    }
+    if invalid != 0 {
+        return Err(ParseHexError::InvalidCharacter);
+    }
+    Ok(bytes)
+}
+
+/// Serialize `bytes` as a hex string for human-readable formats, or as a
+/// tuple of 32 bytes otherwise.
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_bytes<S>(bytes: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::{Error, SerializeTuple};
+
+    if serializer.is_human_readable() {
+        let hex = encode_hex(bytes);
+        let hex = core::str::from_utf8(&hex).map_err(S::Error::custom)?;
+        return serializer.serialize_str(hex);
+    }
+    let mut tup = serializer.serialize_tuple(32)?;
+    for byte in bytes.iter() {
+        tup.serialize_element(byte)?;
+    }
+    tup.end()
+}
+
+/// Deserialize 32 bytes written by `serialize_bytes`.  Human-readable
+/// formats accept either a hex string or a sequence of bytes.
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_bytes<'de, D>(deserializer: D) -> Result<[u8; 32], D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct BytesVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+        type Value = [u8; 32];
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("32 bytes, or a string of 64 hex digits")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<[u8; 32], E>
+        where
+            E: serde::de::Error,
+        {
+            decode_hex(v).map_err(E::custom)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<[u8; 32], A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            let mut bytes = [0u8; 32];
+            #[allow(clippy::needless_range_loop)]
+            for i in 0..32 {
+                bytes[i] = seq
+                    .next_element()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
+            }
+            Ok(bytes)
+        }
+    }
+
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(BytesVisitor)
+    } else {
+        deserializer.deserialize_tuple(32, BytesVisitor)
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::constants;
+    use crate::edwards::CompressedEdwardsY;
+    use crate::montgomery::MontgomeryPoint;
+    use crate::ristretto::CompressedRistretto;
+    use std::string::ToString;
+
+    #[test]
+    fn round_trip() {
+        let ed = constants::ED25519_BASEPOINT_COMPRESSED;
+        let ristretto = constants::RISTRETTO_BASEPOINT_COMPRESSED;
+        let mont = constants::X25519_BASEPOINT;
+
+        assert_eq!(ed.to_string().parse::<CompressedEdwardsY>(), Ok(ed));
+        assert_eq!(
+            ristretto.to_string().parse::<CompressedRistretto>(),
+            Ok(ristretto)
+        );
+        assert_eq!(mont.to_string().parse::<MontgomeryPoint>(), Ok(mont));
+
+        assert_eq!(
+            mont.to_string(),
+            "0900000000000000000000000000000000000000000000000000000000000000"
+        );
+        assert_eq!(format!("{:x}", mont), mont.to_string());
+        assert_eq!(format!("{:#x}", mont), format!("0x{}", mont));
+        assert_eq!(
+            ed.to_string().to_uppercase().parse::<CompressedEdwardsY>(),
+            Ok(ed)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        let text = constants::RISTRETTO_BASEPOINT_COMPRESSED.to_string();
+        assert_eq!(
+            text[..62].parse::<CompressedRistretto>(),
+            Err(ParseHexError::InvalidLength)
+        );
+        assert_eq!(
+            format!("0x{}", text).parse::<CompressedRistretto>(),
+            Err(ParseHexError::InvalidLength)
+        );
+        assert_eq!(
+            format!("{}g", &text[..63]).parse::<CompressedRistretto>(),
+            Err(ParseHexError::InvalidCharacter)
+        );
+    }
+
+    #[test]
+    fn hex_digits() {
+        let bytes: [u8; 32] = core::array::from_fn(|i| (i * 8) as u8 | 0x0a);
+        let hex = encode_hex(&bytes);
+        assert_eq!(
+            &hex[..],
+            &b"0a0a1a1a2a2a3a3a4a4a5a5a6a6a7a7a8a8a9a9aaaaababacacadadaeaeafafa"[..]
+        );
+        assert_eq!(decode_hex(core::str::from_utf8(&hex).unwrap()), Ok(bytes));
+
+        for c in 0..=255u8 {
+            let mut s = [b'0'; 64];
+            s[17] = c;
+            let valid = c.is_ascii_hexdigit();
+            let parsed = core::str::from_utf8(&s).map(decode_hex);
+            match parsed {
+                Ok(Ok(bytes)) => {
+                    assert!(valid);
+                    assert_eq!(bytes[8], (c as char).to_digit(16).unwrap() as u8);
+                }
+                Ok(Err(e)) => {
+                    assert!(!valid);
+                    assert_eq!(e, ParseHexError::InvalidCharacter);
+                }
+                Err(_) => assert!(!valid),
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_human_readable() {
+        use crate::scalar::Scalar;
+        use crate::{EdwardsPoint, RistrettoPoint};
+
+        let ed = constants::ED25519_BASEPOINT_POINT;
+        let ristretto = constants::RISTRETTO_BASEPOINT_POINT;
+        let mont = constants::X25519_BASEPOINT;
+        let s = Scalar::from(0x0102u64);
+
+        let json = serde_json::to_string(&ed).unwrap();
+        assert_eq!(json, format!("\"{}\"", ed.compress()));
+        assert_eq!(serde_json::from_str::<EdwardsPoint>(&json).unwrap(), ed);
+        let json = serde_json::to_string(&ristretto.compress()).unwrap();
+        assert_eq!(json, format!("\"{}\"", ristretto.compress()));
+        assert_eq!(
+            serde_json::from_str::<RistrettoPoint>(&json).unwrap(),
+            ristretto
+        );
+        let json = serde_json::to_string(&mont).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MontgomeryPoint>(&json).unwrap(),
+            mont
+        );
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(
+            json,
+            "\"0201000000000000000000000000000000000000000000000000000000000000\""
+        );
+        assert_eq!(serde_json::from_str::<Scalar>(&json).unwrap(), s);
+
+        // The byte array form is still accepted.
+        let array = serde_json::to_string(s.as_bytes()).unwrap();
+        assert_eq!(serde_json::from_str::<Scalar>(&array).unwrap(), s);
+
+        // Binary formats are unchanged.
+        assert_eq!(bincode::serialize(&s).unwrap(), s.as_bytes());
+        assert_eq!(bincode::serialize(&mont).unwrap(), mont.as_bytes());
+
+        // Values are still validated.
+        let unreduced = format!("\"{}\"", hex::encode([0xff; 32]));
+        assert!(serde_json::from_str::<Scalar>(&unreduced).is_err());
+        assert!(serde_json::from_str::<Scalar>("\"01\"").is_err());
+    }
+
+    #[test]
+    fn rejects_non_canonical_encodings() {
+        // p = 2^255 - 19 is the non-canonical encoding of zero.
+        let p = "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f";
+        assert_eq!(
+            p.parse::<MontgomeryPoint>(),
+            Err(ParseHexError::InvalidEncoding)
+        );
+        assert_eq!(
+            p.parse::<CompressedEdwardsY>(),
+            Err(ParseHexError::InvalidEncoding)
+        );
+        assert_eq!(
+            p.parse::<CompressedRistretto>(),
+            Err(ParseHexError::InvalidEncoding)
+        );
+
+        // The identity with the sign bit of x set.
+        let neg_zero = "0100000000000000000000000000000000000000000000000000000000000080";
+        assert_eq!(
+            neg_zero.parse::<CompressedEdwardsY>(),
+            Err(ParseHexError::InvalidEncoding)
+        );
+
+        // A u-coordinate with the high bit set.
+        let high_bit = "0900000000000000000000000000000000000000000000000000000000000080";
+        assert_eq!(
+            high_bit.parse::<MontgomeryPoint>(),
+            Err(ParseHexError::InvalidEncoding)
+        );
+    }
+}
@@ -22,6 +22,53 @@
 //!
 //! Field operations defined in terms of other field operations, such as
 //! field inversion or square roots, are defined here.
+//!
+//! This module is only public when the `hazmat` feature is enabled.  Its
+//! API is a low-level building block: the representation of a
+//! `FieldElement` is not canonical until it is encoded with `as_bytes`, and
+//! nothing stops a caller from building insecure constructions with it.
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "hazmat", doc = "```")]
+#![cfg_attr(not(feature = "hazmat"), doc = "```ignore")]
+//! use curve25519_dalek::field::FieldElement;
+//!
+//! let mut bytes = [0u8; 32];
+//! bytes[0] = 9;
+//! let x = FieldElement::from_bytes(&bytes);
+//!
+//! let three = x.sqrt().unwrap();
+//! assert_eq!(&three * &three, x);
+//! assert_eq!(&x * &x.invert(), FieldElement::ONE);
+//! ```
+//!
+//! # Constant-time operations
+//!
+//! `FieldElement` implements the [`subtle`] traits
+//! [`ConditionallySelectable`], [`ConditionallyNegatable`] and
+//! [`ConstantTimeEq`].  `conditional_select`, `conditional_assign`,
+//! `conditional_swap` and `conditional_negate` are branch-free and access
+//! the same memory regardless of the `Choice`, so they can be used with
+//! secret conditions.  Comparisons with `==` go through `ct_eq` on the
+//! canonical encodings, but produce a `bool`, so prefer `ct_eq` when the
+//! result is secret.
+//!
+#![cfg_attr(feature = "hazmat", doc = "```")]
+#![cfg_attr(not(feature = "hazmat"), doc = "```ignore")]
+//! use curve25519_dalek::field::FieldElement;
+//! use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable};
+//!
+//! let secret_bit = Choice::from(1);
+//! let mut a = FieldElement::ONE;
+//! let mut b = FieldElement::ZERO;
+//!
+//! FieldElement::conditional_swap(&mut a, &mut b, secret_bit);
+//! assert_eq!((a, b), (FieldElement::ZERO, FieldElement::ONE));
+//!
+//! b.conditional_negate(secret_bit);
+//! assert_eq!(b, FieldElement::MINUS_ONE);
+//! ```
 
 #![allow(unused_qualifications)]
 
@@ -31,6 +78,8 @@ use subtle::Choice;
 use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+#[cfg(feature = "hazmat")]
+use subtle::CtOption;
 
 use crate::backend;
 use crate::constants;
@@ -45,7 +94,7 @@ cfg_if! {
         ///
         /// Using formally-verified field arithmetic from fiat-crypto.
         #[cfg(curve25519_dalek_bits = "32")]
-        pub(crate) type FieldElement = backend::serial::fiat_u32::field::FieldElement2625;
+        pub type FieldElement = backend::serial::fiat_u32::field::FieldElement2625;
 
         /// A `FieldElement` represents an element of the field
         /// \\( \mathbb Z / (2\^{255} - 19)\\).
@@ -55,21 +104,21 @@ cfg_if! {
         ///
         /// Using formally-verified field arithmetic from fiat-crypto.
         #[cfg(curve25519_dalek_bits = "64")]
-        pub(crate) type FieldElement = backend::serial::fiat_u64::field::FieldElement51;
+        pub type FieldElement = backend::serial::fiat_u64::field::FieldElement51;
     } else if #[cfg(curve25519_dalek_bits = "64")] {
         /// A `FieldElement` represents an element of the field
         /// \\( \mathbb Z / (2\^{255} - 19)\\).
         ///
         /// The `FieldElement` type is an alias for one of the platform-specific
         /// implementations.
-        pub(crate) type FieldElement = backend::serial::u64::field::FieldElement51;
+        pub type FieldElement = backend::serial::u64::field::FieldElement51;
     } else {
         /// A `FieldElement` represents an element of the field
         /// \\( \mathbb Z / (2\^{255} - 19)\\).
         ///
         /// The `FieldElement` type is an alias for one of the platform-specific
         /// implementations.
-        pub(crate) type FieldElement = backend::serial::u32::field::FieldElement2625;
+        pub type FieldElement = backend::serial::u32::field::FieldElement2625;
     }
 }
 
@@ -98,7 +147,7 @@ impl FieldElement {
     /// # Return
     ///
     /// If negative, return `Choice(1)`.  Otherwise, return `Choice(0)`.
-    pub(crate) fn is_negative(&self) -> Choice {
+    pub fn is_negative(&self) -> Choice {
         let bytes = self.as_bytes();
         (bytes[0] & 1).into()
     }
@@ -108,7 +157,7 @@ impl FieldElement {
     /// # Return
     ///
     /// If zero, return `Choice(1)`.  Otherwise, return `Choice(0)`.
-    pub(crate) fn is_zero(&self) -> Choice {
+    pub fn is_zero(&self) -> Choice {
         let zero = [0u8; 32];
         let bytes = self.as_bytes();
 
@@ -154,11 +203,13 @@ impl FieldElement {
         (t19, t3)
     }
 
-    /// Given a slice of pub(crate)lic `FieldElements`, replace each with its inverse.
+    /// Given a slice of public `FieldElements`, replace each with its inverse.
     ///
-    /// When an input `FieldElement` is zero, its value is unchanged.
+    /// This costs a single field inversion and three multiplications per
+    /// element.  When an input `FieldElement` is zero, its value is
+    /// unchanged.
     #[cfg(feature = "alloc")]
-    pub(crate) fn batch_invert(inputs: &mut [FieldElement]) {
+    pub fn batch_invert(inputs: &mut [FieldElement]) {
         // Montgomery’s Trick and Fast Implementation of Masked AES
         // Genelle, Prouff and Quisquater
         // Section 3.2
@@ -195,6 +246,60 @@ impl FieldElement {
         }
     }
 
+    /// Given an array of public `FieldElements`, replace each with its
+    /// inverse, without allocating.
+    ///
+    /// When an input `FieldElement` is zero, its value is unchanged.
+    pub fn batch_invert_array<const N: usize>(inputs: &mut [FieldElement; N]) {
+        let mut scratch = [FieldElement::ONE; N];
+        FieldElement::batch_invert_with_scratch(inputs, &mut scratch);
+    }
+
+    /// Given a slice of public `FieldElements`, replace each with its inverse,
+    /// using the caller-provided `scratch` space instead of allocating.
+    ///
+    /// When an input `FieldElement` is zero, its value is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scratch` is shorter than `inputs`.
+    pub fn batch_invert_with_scratch(inputs: &mut [FieldElement], scratch: &mut [FieldElement]) {
+        // Montgomery’s Trick and Fast Implementation of Masked AES
+        // Genelle, Prouff and Quisquater
+        // Section 3.2
+
+        let n = inputs.len();
+        let scratch = &mut scratch[..n];
+
+        // Keep an accumulator of all of the previous products
+        let mut acc = FieldElement::ONE;
+
+        // Pass through the input vector, recording the previous
+        // products in the scratch space
+        for (input, scratch) in inputs.iter().zip(scratch.iter_mut()) {
+            *scratch = acc;
+            // acc <- acc * input, but skipping zeros (constant-time)
+            acc.conditional_assign(&(&acc * input), !input.is_zero());
+        }
+
+        // acc is nonzero because we skipped zeros in inputs
+        assert!(bool::from(!acc.is_zero()));
+
+        // Compute the inverse of all products
+        acc = acc.invert();
+
+        // Pass through the vector backwards to compute the inverses
+        // in place
+        for (input, scratch) in inputs.iter_mut().rev().zip(scratch.iter().rev()) {
+            let tmp = &acc * input;
+            // input <- acc * scratch, then acc <- tmp
+            // Again, we skip zeros in a constant-time way
+            let nz = !input.is_zero();
+            input.conditional_assign(&(&acc * scratch), nz);
+            acc.conditional_assign(&tmp, nz);
+        }
+    }
+
     /// Given a nonzero field element, compute its inverse.
     ///
     /// The inverse is computed as self^(p-2), since
@@ -203,7 +308,7 @@ impl FieldElement {
     /// This function returns zero on input zero.
     #[rustfmt::skip] // keep alignment of explanatory comments
     #[allow(clippy::let_and_return)]
-    pub(crate) fn invert(&self) -> FieldElement {
+    pub fn invert(&self) -> FieldElement {
         // The bits of p-2 = 2^255 -19 -2 are 11010111111...11.
         //
         //                                 nonzero bits of exponent
@@ -214,10 +319,23 @@ impl FieldElement {
         t21
     }
 
+    /// Return `self^(2^k)`, i.e. square this element `k` times, or `None`
+    /// if `k` is zero.
+    #[cfg(feature = "hazmat")]
+    pub fn checked_pow2k(&self, k: u32) -> Option<FieldElement> {
+        if k == 0 {
+            return None;
+        }
+        Some(self.pow2k(k))
+    }
+
     /// Raise this field element to the power (p-5)/8 = 2^252 -3.
+    ///
+    /// This is the exponentiation at the core of square root computations
+    /// such as [`FieldElement::sqrt_ratio_i`].
     #[rustfmt::skip] // keep alignment of explanatory comments
     #[allow(clippy::let_and_return)]
-    fn pow_p58(&self) -> FieldElement {
+    pub fn pow_p58(&self) -> FieldElement {
         // The bits of (p-5)/8 are 101111.....11.
         //
         //                                 nonzero bits of exponent
@@ -240,7 +358,7 @@ impl FieldElement {
     /// - `(Choice(0), zero)        ` if `v` is zero and `u` is nonzero;
     /// - `(Choice(0), +sqrt(i*u/v))` if `u/v` is nonsquare (so `i*u/v` is square).
     ///
-    pub(crate) fn sqrt_ratio_i(u: &FieldElement, v: &FieldElement) -> (Choice, FieldElement) {
+    pub fn sqrt_ratio_i(u: &FieldElement, v: &FieldElement) -> (Choice, FieldElement) {
         // Using the same trick as in ed25519 decoding, we merge the
         // inversion, the square root, and the square test as follows.
         //
@@ -267,9 +385,22 @@ impl FieldElement {
 
         let v3 = &v.square() * v;
         let v7 = &v3.square() * v;
-        let mut r = &(u * &v3) * &(u * &v7).pow_p58();
+        let r = &(u * &v3) * &(u * &v7).pow_p58();
         let check = v * &r.square();
 
+        FieldElement::sqrt_ratio_i_finish(u, r, &check)
+    }
+
+    /// The final step of `sqrt_ratio_i`, given `r = (uv^3)(uv^7)^((p-5)/8)`
+    /// and `check = vr^2`.
+    ///
+    /// This is split out so that vectorized backends can compute `r` and
+    /// `check` for several inputs at once.
+    pub(crate) fn sqrt_ratio_i_finish(
+        u: &FieldElement,
+        mut r: FieldElement,
+        check: &FieldElement,
+    ) -> (Choice, FieldElement) {
         let i = &constants::SQRT_M1;
 
         let correct_sign_sqrt = check.ct_eq(u);
@@ -288,6 +419,20 @@ impl FieldElement {
         (was_nonzero_square, r)
     }
 
+    /// Attempt to compute `sqrt(self)` in constant time.
+    ///
+    /// This function always returns the nonnegative square root.
+    ///
+    /// # Return
+    ///
+    /// - `Some(+sqrt(self))` if `self` is a square (including zero);
+    /// - `None` if `self` is a nonsquare.
+    #[cfg(feature = "hazmat")]
+    pub fn sqrt(&self) -> CtOption<FieldElement> {
+        let (is_square, root) = FieldElement::sqrt_ratio_i(self, &FieldElement::ONE);
+        CtOption::new(root, is_square)
+    }
+
     /// Attempt to compute `sqrt(1/self)` in constant time.
     ///
     /// Convenience wrapper around `sqrt_ratio_i`.
@@ -300,9 +445,88 @@ impl FieldElement {
     /// - `(Choice(0), zero)           ` if `self` is zero;
     /// - `(Choice(0), +sqrt(i/self))  ` if `self` is a nonzero nonsquare;
     ///
-    pub(crate) fn invsqrt(&self) -> (Choice, FieldElement) {
+    pub fn invsqrt(&self) -> (Choice, FieldElement) {
         FieldElement::sqrt_ratio_i(&FieldElement::ONE, self)
     }
+
+    /// Load a `FieldElement` from four little-endian 64-bit words.
+    ///
+    /// This is equivalent to `from_bytes` on the little-endian encoding of
+    /// `words`: the high bit of `words[3]` is ignored, and values in
+    /// \\( [p, 2\^{255}) \\) are accepted and reduced.
+    #[cfg(feature = "hazmat")]
+    pub fn from_u64_words(words: &[u64; 4]) -> FieldElement {
+        let mut bytes = [0u8; 32];
+        for (chunk, word) in bytes.chunks_exact_mut(8).zip(words.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        FieldElement::from_bytes(&bytes)
+    }
+
+    /// Return the canonical representative of this `FieldElement` as four
+    /// little-endian 64-bit words.
+    #[cfg(feature = "hazmat")]
+    pub fn to_u64_words(self) -> [u64; 4] {
+        let bytes = self.as_bytes();
+        let mut words = [0u64; 4];
+        for (i, byte) in bytes.iter().enumerate() {
+            words[i / 8] |= u64::from(*byte) << (8 * (i % 8));
+        }
+        words
+    }
+
+    /// Load a `FieldElement` from eight little-endian 32-bit words.
+    ///
+    /// This is equivalent to `from_bytes` on the little-endian encoding of
+    /// `words`: the high bit of `words[7]` is ignored, and values in
+    /// \\( [p, 2\^{255}) \\) are accepted and reduced.
+    #[cfg(feature = "hazmat")]
+    pub fn from_u32_words(words: &[u32; 8]) -> FieldElement {
+        let mut bytes = [0u8; 32];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(words.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        FieldElement::from_bytes(&bytes)
+    }
+
+    /// Return the canonical representative of this `FieldElement` as eight
+    /// little-endian 32-bit words.
+    #[cfg(feature = "hazmat")]
+    pub fn to_u32_words(self) -> [u32; 8] {
+        let bytes = self.as_bytes();
+        let mut words = [0u32; 8];
+        for (i, byte) in bytes.iter().enumerate() {
+            words[i / 4] |= u32::from(*byte) << (8 * (i % 4));
+        }
+        words
+    }
+
+    /// Load a `FieldElement` from 64 bytes, by reducing the little-endian
+    /// 512-bit integer they encode modulo \\( p \\).
+    ///
+    /// The result is statistically close to uniform when the input is
+    /// uniformly random, as required for `hash_to_field` in RFC 9380.
+    #[cfg(feature = "hazmat")]
+    pub fn from_bytes_wide(bytes: &[u8; 64]) -> FieldElement {
+        let mut lo = [0u8; 32];
+        let mut hi = [0u8; 32];
+        lo.copy_from_slice(&bytes[..32]);
+        hi.copy_from_slice(&bytes[32..]);
+
+        // `from_bytes` ignores the top bit of each half, so fold those bits
+        // back in using 2^255 = 19 and 2^511 = 2 * 19^2 (mod p).
+        let top_bits = 19 * u16::from(lo[31] >> 7) + 722 * u16::from(hi[31] >> 7);
+        let mut top = [0u8; 32];
+        top[..2].copy_from_slice(&top_bits.to_le_bytes());
+
+        // 2^256 = 38 (mod p).
+        let mut thirty_eight = [0u8; 32];
+        thirty_eight[0] = 38;
+
+        let lo = &FieldElement::from_bytes(&lo) + &FieldElement::from_bytes(&top);
+        let hi = &FieldElement::from_bytes(&hi) * &FieldElement::from_bytes(&thirty_eight);
+        &lo + &hi
+    }
 }
 
 #[cfg(test)]
@@ -386,6 +610,20 @@ mod test {
         }
     }
 
+    #[test]
+    fn batch_invert_array_matches_nonbatched() {
+        let a = FieldElement::from_bytes(&A_BYTES);
+        let asq = FieldElement::from_bytes(&ASQ_BYTES);
+        let a_list = [a, asq, FieldElement::ZERO, FieldElement::ONE];
+        let mut ainv_list = a_list;
+        FieldElement::batch_invert_array(&mut ainv_list);
+        for (a, ainv) in a_list.iter().zip(&ainv_list) {
+            assert_eq!(a.invert(), *ainv);
+        }
+
+        FieldElement::batch_invert_array(&mut []);
+    }
+
     #[test]
     fn sqrt_ratio_behavior() {
         let zero = FieldElement::ZERO;
@@ -425,6 +663,154 @@ mod test {
         assert!(bool::from(!sqrt.is_negative()));
     }
 
+    #[test]
+    fn invsqrt_behavior() {
+        let one = FieldElement::ONE;
+        let two = &one + &one; // 2 is nonsquare mod p.
+        let four = &two + &two; // 4 is square mod p.
+
+        let (choice, isr) = FieldElement::ZERO.invsqrt();
+        assert!(bool::from(!choice));
+        assert_eq!(isr, FieldElement::ZERO);
+
+        let (choice, isr) = four.invsqrt();
+        assert!(bool::from(choice));
+        assert_eq!(&isr.square() * &four, one);
+        assert!(bool::from(!isr.is_negative()));
+
+        let (choice, isr) = two.invsqrt();
+        assert!(bool::from(!choice));
+        assert_eq!(&isr.square() * &two, constants::SQRT_M1);
+    }
+
+    #[test]
+    #[cfg(feature = "hazmat")]
+    fn sqrt_behavior() {
+        let one = FieldElement::ONE;
+        let two = &one + &one; // 2 is nonsquare mod p.
+        let a = FieldElement::from_bytes(&A_BYTES);
+        let asq = FieldElement::from_bytes(&ASQ_BYTES);
+
+        assert_eq!(FieldElement::ZERO.sqrt().unwrap(), FieldElement::ZERO);
+        assert!(bool::from(two.sqrt().is_none()));
+
+        let root = asq.sqrt().unwrap();
+        assert!(root == a || root == -&a);
+        assert!(bool::from(!root.is_negative()));
+    }
+
+    #[test]
+    #[cfg(feature = "hazmat")]
+    fn mul_add() {
+        let a = FieldElement::from_bytes(&A_BYTES);
+        let asq = FieldElement::from_bytes(&ASQ_BYTES);
+        let minus_one = FieldElement::MINUS_ONE;
+
+        assert_eq!(a.mul_add(&a, &FieldElement::ZERO), asq);
+        assert_eq!(a.mul_add(&a, &minus_one), &asq - &FieldElement::ONE);
+        assert_eq!(
+            minus_one.mul_add(&minus_one, &minus_one),
+            FieldElement::ZERO
+        );
+
+        // Unreduced inputs, as produced by addition
+        let two_a = &a + &a;
+        let two_asq = &asq + &asq;
+        assert_eq!(
+            two_a.mul_add(&two_a, &two_asq),
+            &(&two_a * &two_a) + &two_asq
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "hazmat")]
+    fn from_bytes_wide_reduces_mod_p() {
+        // (2^512 - 1) mod p
+        let mut expected = [0u8; 32];
+        expected[0] = 0xa3;
+        expected[1] = 0x05;
+        assert_eq!(
+            FieldElement::from_bytes_wide(&[0xff; 64]).as_bytes(),
+            expected
+        );
+
+        // SHA-512("curve25519-dalek") mod p
+        let input = hex::decode(
+            "2be85140febeda61fcd08c67a7cdf6b9275968b49a4b76eec884e27843a61bb4\
+             41d3cb4ff629c5c018864c038e45cc341049dd84992e5daee32afa92570f232b",
+        )
+        .unwrap();
+        let expected: [u8; 32] = [
+            219, 68, 147, 24, 142, 249, 30, 255, 168, 184, 232, 228, 187, 32, 73, 144, 143, 49, 65,
+            109, 100, 54, 75, 208, 148, 226, 4, 74, 67, 237, 79, 27,
+        ];
+        assert_eq!(
+            FieldElement::from_bytes_wide(&input.try_into().unwrap()).as_bytes(),
+            expected
+        );
+
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&A_BYTES);
+        assert_eq!(
+            FieldElement::from_bytes_wide(&wide),
+            FieldElement::from_bytes(&A_BYTES)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "hazmat")]
+    fn word_conversions() {
+        let a = FieldElement::from_bytes(&A_BYTES);
+
+        let words64 = a.to_u64_words();
+        assert_eq!(
+            words64[0],
+            u64::from_le_bytes(A_BYTES[..8].try_into().unwrap())
+        );
+        assert_eq!(FieldElement::from_u64_words(&words64), a);
+
+        let words32 = a.to_u32_words();
+        assert_eq!(
+            words32[0],
+            u32::from_le_bytes(A_BYTES[..4].try_into().unwrap())
+        );
+        assert_eq!(FieldElement::from_u32_words(&words32), a);
+
+        // p itself is accepted and reduced to the canonical zero.
+        let p = [u64::MAX - 18, u64::MAX, u64::MAX, u64::MAX >> 1];
+        assert_eq!(FieldElement::from_u64_words(&p).to_u64_words(), [0; 4]);
+
+        // The high bit is ignored.
+        let mut high_bit = [0u32; 8];
+        high_bit[7] = 1 << 31;
+        assert_eq!(FieldElement::from_u32_words(&high_bit), FieldElement::ZERO);
+    }
+
+    #[test]
+    #[cfg(feature = "hazmat")]
+    fn from_raw_limbs() {
+        // 2^51 + 1
+        #[cfg(curve25519_dalek_bits = "64")]
+        const X: FieldElement = FieldElement::from_raw_limbs([1, 1, 0, 0, 0]);
+        #[cfg(curve25519_dalek_bits = "32")]
+        const X: FieldElement = FieldElement::from_raw_limbs([1, 0, 1, 0, 0, 0, 0, 0, 0, 0]);
+
+        let mut bytes = [0u8; 32];
+        bytes[0] = 1;
+        bytes[6] = 1 << 3;
+        assert_eq!(X, FieldElement::from_bytes(&bytes));
+    }
+
+    #[test]
+    #[cfg(all(feature = "hazmat", debug_assertions))]
+    #[should_panic(expected = "limb out of range")]
+    fn from_raw_limbs_out_of_range() {
+        #[cfg(curve25519_dalek_bits = "64")]
+        FieldElement::from_raw_limbs([0, u64::MAX, 0, 0, 0]);
+        #[cfg(curve25519_dalek_bits = "32")]
+        FieldElement::from_raw_limbs([0, u32::MAX, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
     #[test]
     fn a_p58_vs_ap58_constant() {
         let a = FieldElement::from_bytes(&A_BYTES);
@@ -432,6 +818,24 @@ mod test {
         assert_eq!(ap58, a.pow_p58());
     }
 
+    #[test]
+    fn pow2k_vs_repeated_squaring() {
+        let a = FieldElement::from_bytes(&A_BYTES);
+        let mut expected = a;
+        for k in 1..=20 {
+            expected = expected.square();
+            assert_eq!(a.pow2k(k), expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "hazmat")]
+    fn checked_pow2k() {
+        let a = FieldElement::from_bytes(&A_BYTES);
+        assert_eq!(a.checked_pow2k(0), None);
+        assert_eq!(a.checked_pow2k(3), Some(a.pow2k(3)));
+    }
+
     #[test]
     fn equality() {
         let a = FieldElement::from_bytes(&A_BYTES);
@@ -456,6 +860,32 @@ mod test {
         assert_eq!(without_highbit_set, with_highbit_set);
     }
 
+    #[test]
+    fn conditional_select_and_swap() {
+        let a = FieldElement::from_bytes(&A_BYTES);
+        let asq = FieldElement::from_bytes(&ASQ_BYTES);
+
+        assert_eq!(
+            FieldElement::conditional_select(&a, &asq, Choice::from(0)),
+            a
+        );
+        assert_eq!(
+            FieldElement::conditional_select(&a, &asq, Choice::from(1)),
+            asq
+        );
+
+        let (mut x, mut y) = (a, asq);
+        FieldElement::conditional_swap(&mut x, &mut y, Choice::from(0));
+        assert_eq!((x, y), (a, asq));
+        FieldElement::conditional_swap(&mut x, &mut y, Choice::from(1));
+        assert_eq!((x, y), (asq, a));
+
+        x.conditional_assign(&a, Choice::from(0));
+        assert_eq!(x, asq);
+        x.conditional_assign(&a, Choice::from(1));
+        assert_eq!(x, a);
+    }
+
     #[test]
     fn conditional_negate() {
         let one = FieldElement::ONE;
@@ -0,0 +1,247 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Structured input generation for fuzzers and property tests.
+//!
+//! The point and scalar types implement [`arbitrary::Arbitrary`], so they
+//! can be used directly in `cargo fuzz` targets:
+//!
+//! * `Scalar`s are reductions of 32 input bytes.
+//! * `EdwardsPoint`s are \\(sB + T\\) for an arbitrary scalar \\(s\\) and
+//!   an arbitrary 8-torsion point \\(T\\), so that points both in and
+//!   outside the prime-order subgroup are generated.
+//! * `RistrettoPoint`s are \\(sB\\), stored with an arbitrary
+//!   representative of their coset, which exercises the equality and
+//!   compression code on every internal representation of a group element.
+//! * `MontgomeryPoint`, `CompressedEdwardsY` and `CompressedRistretto`
+//!   are arbitrary byte strings, which include points on the twist,
+//!   invalid encodings and non-canonical encodings.
+//!
+//! The [`strategy`] module provides [`proptest`] strategies for the same
+//! types, with the valid, torsioned and non-canonical cases separated.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::constants::EIGHT_TORSION;
+use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
+use crate::montgomery::MontgomeryPoint;
+use crate::ristretto::{CompressedRistretto, RistrettoPoint};
+use crate::scalar::Scalar;
+
+impl<'a> Arbitrary<'a> for Scalar {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Scalar::from_bytes_mod_order(u.arbitrary()?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (32, Some(32))
+    }
+}
+
+impl<'a> Arbitrary<'a> for EdwardsPoint {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let torsion = EIGHT_TORSION[u.int_in_range(0..=7)?];
+        Ok(EdwardsPoint::mul_base(&Scalar::arbitrary(u)?) + torsion)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (33, Some(33))
+    }
+}
+
+impl<'a> Arbitrary<'a> for RistrettoPoint {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Adding a 4-torsion point changes the representative but not the
+        // group element.
+        let torsion = EIGHT_TORSION[2 * u.int_in_range(0..=3)?];
+        let point = RistrettoPoint::mul_base(&Scalar::arbitrary(u)?);
+        Ok(RistrettoPoint(point.0 + torsion))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (33, Some(33))
+    }
+}
+
+macro_rules! impl_arbitrary_bytes {
+    ($($ty:ident),*) => {
+        $(
+            impl<'a> Arbitrary<'a> for $ty {
+                fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                    Ok($ty(u.arbitrary()?))
+                }
+
+                fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                    (32, Some(32))
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_bytes!(MontgomeryPoint, CompressedEdwardsY, CompressedRistretto);
+
+/// [`proptest`] strategies for points and scalars.
+pub mod strategy {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// The little-endian encoding of \\(p + k\\), for \\(0 \leq k < 19\\),
+    /// which is a non-canonical encoding of the field element \\(k\\).
+    fn p_plus(k: u8) -> [u8; 32] {
+        let mut bytes = [0xff; 32];
+        bytes[0] = 0xed + k;
+        bytes[31] = 0x7f;
+        bytes
+    }
+
+    /// Scalars, uniformly distributed.
+    pub fn scalar() -> impl Strategy<Value = Scalar> {
+        any::<[u8; 64]>().prop_map(|bytes| Scalar::from_bytes_mod_order_wide(&bytes))
+    }
+
+    /// Points in the prime-order subgroup.
+    pub fn edwards_point() -> impl Strategy<Value = EdwardsPoint> {
+        scalar().prop_map(|s| EdwardsPoint::mul_base(&s))
+    }
+
+    /// Points with a nonzero torsion component, i.e. outside the
+    /// prime-order subgroup.
+    pub fn torsioned_edwards_point() -> impl Strategy<Value = EdwardsPoint> {
+        (edwards_point(), 1..8usize).prop_map(|(point, i)| point + EIGHT_TORSION[i])
+    }
+
+    /// Non-canonical encodings of \\(y\\)-coordinates, i.e. those with
+    /// \\(p \leq y < 2\^{255}\\), with either sign bit.  Some of these
+    /// decompress to points.
+    pub fn non_canonical_compressed_edwards_y() -> impl Strategy<Value = CompressedEdwardsY> {
+        (0..19u8, any::<bool>()).prop_map(|(k, sign)| {
+            let mut bytes = p_plus(k);
+            bytes[31] |= (sign as u8) << 7;
+            CompressedEdwardsY(bytes)
+        })
+    }
+
+    /// Ristretto points, stored with any representative of their coset.
+    pub fn ristretto_point() -> impl Strategy<Value = RistrettoPoint> {
+        (scalar(), 0..4usize).prop_map(|(s, i)| {
+            RistrettoPoint(RistrettoPoint::mul_base(&s).0 + EIGHT_TORSION[2 * i])
+        })
+    }
+
+    /// Non-canonical Ristretto encodings, none of which decompress: an
+    /// \\(s\\) of at least \\(p\\), a negative \\(s\\), or a set high bit.
+    pub fn non_canonical_compressed_ristretto() -> impl Strategy<Value = CompressedRistretto> {
+        prop_oneof![
+            (0..19u8).prop_map(p_plus),
+            any::<[u8; 32]>().prop_map(|mut bytes| {
+                bytes[0] |= 1;
+                bytes[31] &= 0x7f;
+                bytes
+            }),
+            any::<[u8; 32]>().prop_map(|mut bytes| {
+                bytes[31] |= 0x80;
+                bytes
+            }),
+        ]
+        .prop_map(CompressedRistretto)
+    }
+
+    /// \\(u\\)-coordinates of points in the prime-order subgroup.
+    pub fn montgomery_point() -> impl Strategy<Value = MontgomeryPoint> {
+        edwards_point().prop_map(|point| point.to_montgomery())
+    }
+
+    /// Non-canonical \\(u\\)-coordinates: those of at least \\(p\\), or with
+    /// the unused high bit set.
+    pub fn non_canonical_montgomery_point() -> impl Strategy<Value = MontgomeryPoint> {
+        prop_oneof![
+            (0..19u8).prop_map(p_plus),
+            any::<[u8; 32]>().prop_map(|mut bytes| {
+                bytes[31] |= 0x80;
+                bytes
+            }),
+        ]
+        .prop_map(MontgomeryPoint)
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::strategy::*;
+    use super::*;
+
+    use proptest::prelude::*;
+    use std::string::ToString;
+
+    #[test]
+    fn arbitrary_edwards_point_torsion() {
+        // The first byte chooses the torsion component.
+        let mut bytes = [0x5a; 33];
+        bytes[0] = 0;
+        let point = EdwardsPoint::arbitrary(&mut Unstructured::new(&bytes)).expect("enough data");
+        assert!(point.is_torsion_free());
+
+        bytes[0] = 3;
+        let point = EdwardsPoint::arbitrary(&mut Unstructured::new(&bytes)).expect("enough data");
+        assert!(!point.is_torsion_free());
+    }
+
+    #[test]
+    fn arbitrary_ristretto_point_representatives() {
+        let mut bytes = [0x5a; 33];
+        let expected =
+            RistrettoPoint::arbitrary(&mut Unstructured::new(&bytes)).expect("enough data");
+        for i in 1..4 {
+            bytes[0] = i;
+            let point =
+                RistrettoPoint::arbitrary(&mut Unstructured::new(&bytes)).expect("enough data");
+            assert_eq!(point, expected);
+            assert_eq!(point.compress(), expected.compress());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn strategy_edwards_points(
+            point in edwards_point(),
+            torsioned in torsioned_edwards_point(),
+        ) {
+            prop_assert!(point.is_torsion_free());
+            prop_assert!(!torsioned.is_torsion_free());
+        }
+
+        #[test]
+        fn strategy_non_canonical_edwards_y(encoding in non_canonical_compressed_edwards_y()) {
+            if let Some(point) = encoding.decompress() {
+                prop_assert_ne!(point.compress(), encoding);
+            }
+        }
+
+        #[test]
+        fn strategy_ristretto_points(point in ristretto_point()) {
+            prop_assert_eq!(point.compress().decompress(), Some(point));
+        }
+
+        #[test]
+        fn strategy_non_canonical_ristretto(encoding in non_canonical_compressed_ristretto()) {
+            prop_assert!(encoding.decompress().is_none());
+        }
+
+        #[test]
+        fn strategy_montgomery_points(
+            point in montgomery_point(),
+            non_canonical in non_canonical_montgomery_point(),
+        ) {
+            prop_assert!(point.to_edwards(0).is_some());
+            prop_assert!(non_canonical.to_string().parse::<MontgomeryPoint>().is_err());
+        }
+    }
+}
@@ -10,18 +10,6 @@
 // - Henry de Valence <hdevalence@hdevalence.ca>
 
 #![no_std]
-#![cfg_attr(
-    all(
-        curve25519_dalek_backend = "simd",
-        nightly,
-        any(target_arch = "x86", target_arch = "x86_64")
-    ),
-    feature(stdarch_x86_avx512)
-)]
-#![cfg_attr(
-    all(curve25519_dalek_backend = "simd", nightly),
-    feature(avx512_target_feature)
-)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg, doc_cfg, doc_cfg_hide))]
 #![cfg_attr(docsrs, doc(cfg_hide(docsrs)))]
 //------------------------------------------------------------------------
@@ -83,14 +71,38 @@ pub mod ristretto;
 // Useful constants, like the Ed25519 basepoint
 pub mod constants;
 
+// Hex text encodings of points
+pub mod encoding;
+
 // External (and internal) traits.
 pub mod traits;
 
+// Feldman verifiable secret sharing
+pub mod vss;
+
+// Schnorr proofs of knowledge of a discrete logarithm
+#[cfg(feature = "digest")]
+pub mod sigma;
+
+// Blinding and evaluation steps of an oblivious pseudorandom function
+pub mod oprf;
+
+// COSE_Key and JWK encodings of X25519 and Ed25519 public keys
+#[cfg(feature = "okp")]
+pub mod okp;
+
+// Arbitrary implementations and proptest strategies for fuzzing
+#[cfg(feature = "fuzzing")]
+pub mod fuzzing;
+
 //------------------------------------------------------------------------
 // curve25519-dalek internal modules
 //------------------------------------------------------------------------
 
 // Finite field arithmetic mod p = 2^255 - 19
+#[cfg(feature = "hazmat")]
+pub mod field;
+#[cfg(not(feature = "hazmat"))]
 pub(crate) mod field;
 
 // Arithmetic backends (using u32, u64, etc) live here
@@ -99,6 +111,18 @@ pub mod backend;
 #[cfg(not(docsrs))]
 pub(crate) mod backend;
 
+// Vectorized field arithmetic, when the SIMD backend is compiled in
+#[cfg(all(feature = "hazmat", curve25519_dalek_backend = "simd"))]
+pub mod vector;
+
+// Differential checks of the selected backend against the serial backend
+#[cfg(feature = "hazmat")]
+pub use crate::backend::differential;
+
+// Runtime-selectable backends, for overriding the automatic choice
+#[cfg(feature = "hazmat")]
+pub use crate::backend::Backend;
+
 // Generic code for window lookups
 pub(crate) mod window;
 
@@ -49,6 +49,10 @@
 // affine and projective cakes and eat both of them too.
 #![allow(non_snake_case)]
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::borrow::Borrow;
 use core::{
     hash::{Hash, Hasher},
     ops::{Mul, MulAssign},
@@ -56,10 +60,13 @@ use core::{
 
 use crate::constants::{APLUS2_OVER_FOUR, MONTGOMERY_A, MONTGOMERY_A_NEG};
 use crate::edwards::{CompressedEdwardsY, EdwardsPoint};
+use crate::encoding::ParseHexError;
 use crate::field::FieldElement;
 use crate::scalar::{clamp_integer, Scalar};
 
 use crate::traits::Identity;
+#[cfg(feature = "alloc")]
+use crate::traits::VartimeMultiscalarMul;
 
 use subtle::Choice;
 use subtle::ConstantTimeEq;
@@ -71,7 +78,7 @@ use zeroize::Zeroize;
 /// Holds the \\(u\\)-coordinate of a point on the Montgomery form of
 /// Curve25519 or its twist.
 #[derive(Copy, Clone, Debug, Default)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
 pub struct MontgomeryPoint(pub [u8; 32]);
 
 /// Equality of `MontgomeryPoint`s is defined mod p.
@@ -103,6 +110,64 @@ impl Hash for MontgomeryPoint {
     }
 }
 
+impl core::fmt::Display for MontgomeryPoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::encoding::fmt_hex(self.as_bytes(), f)
+    }
+}
+
+impl core::fmt::LowerHex for MontgomeryPoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::encoding::fmt_hex(self.as_bytes(), f)
+    }
+}
+
+impl core::str::FromStr for MontgomeryPoint {
+    type Err = ParseHexError;
+
+    /// Parse 64 hex digits, accepting only a canonical \\(u\\)-coordinate,
+    /// i.e. one less than \\(p\\).
+    fn from_str(s: &str) -> Result<MontgomeryPoint, ParseHexError> {
+        let bytes = crate::encoding::decode_hex(s)?;
+        if FieldElement::from_bytes(&bytes).as_bytes() == bytes {
+            Ok(MontgomeryPoint(bytes))
+        } else {
+            Err(ParseHexError::InvalidEncoding)
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for MontgomeryPoint {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "MontgomeryPoint({=u64:016x}...)",
+            crate::encoding::short_hex(self.as_bytes())
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MontgomeryPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::encoding::serialize_bytes(self.as_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MontgomeryPoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::encoding::deserialize_bytes(deserializer).map(MontgomeryPoint)
+    }
+}
+
 impl Identity for MontgomeryPoint {
     /// Return the group identity element, which has order 4.
     fn identity() -> MontgomeryPoint {
@@ -117,6 +182,111 @@ impl Zeroize for MontgomeryPoint {
     }
 }
 
+// SAFETY: `MontgomeryPoint` is a `#[repr(transparent)]` `[u8; 32]`, and every
+// \(u\)-coordinate encoding is a valid point on the curve or its twist.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for MontgomeryPoint {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for MontgomeryPoint {}
+
+// ------------------------------------------------------------------------
+// PKCS#8 and SPKI
+// ------------------------------------------------------------------------
+
+/// The X25519 `AlgorithmIdentifier` of RFC 8410, which has no parameters.
+#[cfg(feature = "pkcs8")]
+const X25519_ALGORITHM_ID: pkcs8::AlgorithmIdentifierRef<'static> = pkcs8::AlgorithmIdentifierRef {
+    oid: pkcs8::ObjectIdentifier::new_unwrap("1.3.101.110"),
+    parameters: None,
+};
+
+/// Check that `algorithm` is X25519, without parameters.
+#[cfg(feature = "pkcs8")]
+fn check_x25519_algorithm(
+    algorithm: &pkcs8::AlgorithmIdentifierRef<'_>,
+) -> pkcs8::spki::Result<()> {
+    algorithm.assert_algorithm_oid(X25519_ALGORITHM_ID.oid)?;
+    if algorithm.parameters.is_some() {
+        return Err(pkcs8::spki::Error::KeyMalformed);
+    }
+    Ok(())
+}
+
+/// Decode an X25519 public key from a `SubjectPublicKeyInfo` (RFC 8410).
+/// This gives `MontgomeryPoint` an implementation of
+/// [`DecodePublicKey`](pkcs8::DecodePublicKey).
+#[cfg(feature = "pkcs8")]
+impl TryFrom<pkcs8::SubjectPublicKeyInfoRef<'_>> for MontgomeryPoint {
+    type Error = pkcs8::spki::Error;
+
+    fn try_from(spki: pkcs8::SubjectPublicKeyInfoRef<'_>) -> pkcs8::spki::Result<Self> {
+        check_x25519_algorithm(&spki.algorithm)?;
+        spki.subject_public_key
+            .as_bytes()
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(MontgomeryPoint)
+            .ok_or(pkcs8::spki::Error::KeyMalformed)
+    }
+}
+
+/// Encode an X25519 public key as a `SubjectPublicKeyInfo` (RFC 8410).
+#[cfg(all(feature = "pkcs8", feature = "alloc"))]
+impl pkcs8::EncodePublicKey for MontgomeryPoint {
+    fn to_public_key_der(&self) -> pkcs8::spki::Result<pkcs8::Document> {
+        let spki = pkcs8::SubjectPublicKeyInfoRef {
+            algorithm: X25519_ALGORITHM_ID,
+            subject_public_key: pkcs8::der::asn1::BitStringRef::new(0, &self.0)?,
+        };
+        Ok(pkcs8::Document::encode_msg(&spki)?)
+    }
+}
+
+/// Wrap an X25519 secret key in a PKCS#8 `PrivateKeyInfo` (RFC 8410).
+///
+/// The secret is stored as given.  X25519 clamps secrets when they are
+/// used, as [`MontgomeryPoint::mul_clamped`] does, so a clamped secret and
+/// the raw bytes it was clamped from are equally valid here.
+#[cfg(all(feature = "pkcs8", feature = "alloc"))]
+pub fn secret_to_pkcs8_der(secret: &[u8; 32]) -> pkcs8::Result<pkcs8::SecretDocument> {
+    // The private key is itself a DER OCTET STRING holding the secret.
+    let mut private_key = [0u8; 34];
+    private_key[0] = 0x04;
+    private_key[1] = 0x20;
+    private_key[2..].copy_from_slice(secret);
+
+    let result = pkcs8::SecretDocument::encode_msg(&pkcs8::PrivateKeyInfo::new(
+        X25519_ALGORITHM_ID,
+        &private_key,
+    ));
+
+    #[cfg(feature = "zeroize")]
+    private_key.zeroize();
+
+    Ok(result?)
+}
+
+/// Unwrap an X25519 secret key from a PKCS#8 `PrivateKeyInfo` (RFC 8410).
+///
+/// If the structure also holds the public key, as PKCS#8 v2 allows, it is
+/// checked against the secret.
+#[cfg(feature = "pkcs8")]
+pub fn secret_from_pkcs8_der(der: &[u8]) -> pkcs8::Result<[u8; 32]> {
+    let info = pkcs8::PrivateKeyInfo::try_from(der)?;
+    check_x25519_algorithm(&info.algorithm)?;
+
+    let secret: [u8; 32] = match info.private_key {
+        [0x04, 0x20, secret @ ..] => secret.try_into().map_err(|_| pkcs8::Error::KeyMalformed)?,
+        _ => return Err(pkcs8::Error::KeyMalformed),
+    };
+    if let Some(public_key) = info.public_key {
+        if MontgomeryPoint::mul_base_clamped(secret).as_bytes()[..] != *public_key {
+            return Err(pkcs8::Error::KeyMalformed);
+        }
+    }
+    Ok(secret)
+}
+
 impl MontgomeryPoint {
     /// Fixed-base scalar multiplication (i.e. multiplication by the base point).
     pub fn mul_base(scalar: &Scalar) -> Self {
@@ -151,6 +321,30 @@ impl MontgomeryPoint {
         Self::mul_base(&s)
     }
 
+    /// Multiply four points by four scalars at once, returning
+    /// `[&points[0] * &scalars[0], ..., &points[3] * &scalars[3]]`.
+    ///
+    /// On CPUs with AVX2 or AVX512 IFMA the four Montgomery ladders run in
+    /// parallel vector lanes, which is faster than four separate
+    /// multiplications.  This is constant time in all four scalars.
+    pub fn mul_x4(points: &[MontgomeryPoint; 4], scalars: &[Scalar; 4]) -> [MontgomeryPoint; 4] {
+        crate::backend::montgomery_mul_x4(points, scalars)
+    }
+
+    /// Multiply four points by `clamp_integer` of four byte strings at once,
+    /// as in four independent X25519 key exchanges.  See [`Self::mul_x4`]
+    /// and [`Self::mul_clamped`].
+    pub fn mul_clamped_x4(
+        points: &[MontgomeryPoint; 4],
+        bytes: [[u8; 32]; 4],
+    ) -> [MontgomeryPoint; 4] {
+        // See reasoning in Self::mul_clamped why it is OK to make unreduced Scalars here.
+        let scalars = bytes.map(|b| Scalar {
+            bytes: clamp_integer(b),
+        });
+        Self::mul_x4(points, &scalars)
+    }
+
     /// Given `self` \\( = u\_0(P) \\), and a big-endian bit representation of an integer
     /// \\(n\\), return \\( u\_0(\[n\]P) \\). This is constant time in the length of `bits`.
     ///
@@ -401,7 +595,7 @@ impl Mul<&Scalar> for &MontgomeryPoint {
     fn mul(self, scalar: &Scalar) -> MontgomeryPoint {
         // We multiply by the integer representation of the given Scalar. By scalar invariant #1,
         // the MSB is 0, so we can skip it.
-        self.mul_bits_be(scalar.bits_le().rev().skip(1))
+        self.mul_bits_be(scalar.bits_be().skip(1))
     }
 }
 
@@ -419,6 +613,55 @@ impl Mul<&MontgomeryPoint> for &Scalar {
     }
 }
 
+// ------------------------------------------------------------------------
+// Multiscalar Multiplication impls
+// ------------------------------------------------------------------------
+
+/// Multiscalar multiplication of \\(u\\)-coordinates.
+///
+/// A \\(u\\)-coordinate determines a curve point only up to sign, and the
+/// \\(u\\)-coordinate of a sum depends on the signs of the summands.  Each
+/// input is lifted with [`MontgomeryPoint::to_edwards`] using sign `0`,
+/// an arbitrary choice, and the result is \\(u(\sum c\_i P\_i)\\) for
+/// those lifts.  It is **not** determined by the \\(u\\)-coordinates alone:
+/// lifting any input with the other sign can change the result.  Protocols
+/// using this must therefore be defined in terms of this lift.
+///
+/// A single point needs no lift and uses the Montgomery ladder, which also
+/// accepts points on the twist.  With more than one point, a point on the
+/// twist has no lift, no sum is defined, and the result is `None`.
+///
+/// There is no constant-time `MultiscalarMul` impl, as it would have no way
+/// to report a point on the twist.
+#[cfg(feature = "alloc")]
+impl VartimeMultiscalarMul for MontgomeryPoint {
+    type Point = MontgomeryPoint;
+
+    /// Returns \\(u(\sum c\_i P\_i)\\), where each \\(P\_i\\) is the lift of
+    /// the input with sign `0`.
+    ///
+    /// Returns `None` if any point is `None`, or if there is more than one
+    /// point and one of them is on the twist.
+    fn optional_multiscalar_mul<I, J>(scalars: I, points: J) -> Option<MontgomeryPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator<Item = Option<MontgomeryPoint>>,
+    {
+        let scalars: Vec<Scalar> = scalars.into_iter().map(|s| *s.borrow()).collect();
+        let points = points
+            .into_iter()
+            .collect::<Option<Vec<MontgomeryPoint>>>()?;
+        assert_eq!(scalars.len(), points.len());
+
+        if points.len() == 1 {
+            return Some(points[0] * scalars[0]);
+        }
+        let lifted = points.iter().map(|P| P.to_edwards(0));
+        EdwardsPoint::optional_multiscalar_mul(scalars, lifted).map(|P| P.to_montgomery())
+    }
+}
+
 // ------------------------------------------------------------------------
 // Tests
 // ------------------------------------------------------------------------
@@ -433,6 +676,46 @@ mod test {
 
     use rand_core::{CryptoRng, RngCore};
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn multiscalar_mul_matches_lifted_sum() {
+        use crate::traits::VartimeMultiscalarMul;
+
+        let mut csprng = rand_core::OsRng;
+        let scalars: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut csprng)).collect();
+        let points: Vec<MontgomeryPoint> = (0..5)
+            .map(|_| EdwardsPoint::mul_base(&Scalar::random(&mut csprng)).to_montgomery())
+            .collect();
+
+        let expected = scalars
+            .iter()
+            .zip(&points)
+            .fold(EdwardsPoint::identity(), |acc, (s, P)| {
+                acc + P.to_edwards(0).expect("points are in the subgroup") * s
+            })
+            .to_montgomery();
+        assert_eq!(
+            MontgomeryPoint::vartime_multiscalar_mul(&scalars, &points),
+            expected
+        );
+
+        // A single point, even on the twist, uses the ladder.
+        let twist = MontgomeryPoint(FieldElement::MINUS_ONE.as_bytes());
+        assert_eq!(
+            MontgomeryPoint::vartime_multiscalar_mul(&scalars[..1], [twist]),
+            twist * scalars[0]
+        );
+
+        // Sums involving twist points are not defined.
+        assert_eq!(
+            MontgomeryPoint::optional_multiscalar_mul(
+                &scalars[..2],
+                [Some(twist), Some(points[1])]
+            ),
+            None
+        );
+    }
+
     #[test]
     fn identity_in_different_coordinates() {
         let id_projective = ProjectivePoint::identity();
@@ -632,6 +915,85 @@ mod test {
         }
     }
 
+    #[test]
+    fn mul_x4_matches_mul() {
+        let mut csprng = rand_core::OsRng;
+
+        // Include the identity and a zero scalar among random inputs.
+        let points = [
+            rand_prime_order_point(csprng).to_montgomery(),
+            MontgomeryPoint::identity(),
+            rand_prime_order_point(csprng).to_montgomery(),
+            rand_prime_order_point(csprng).to_montgomery(),
+        ];
+        let scalars = [
+            Scalar::random(&mut csprng),
+            Scalar::random(&mut csprng),
+            Scalar::ZERO,
+            Scalar::random(&mut csprng),
+        ];
+        let expected = [0, 1, 2, 3].map(|i| points[i] * scalars[i]);
+        assert_eq!(MontgomeryPoint::mul_x4(&points, &scalars), expected);
+
+        let mut bytes = [[0u8; 32]; 4];
+        for b in bytes.iter_mut() {
+            csprng.fill_bytes(b);
+        }
+        let expected = [0, 1, 2, 3].map(|i| points[i].mul_clamped(bytes[i]));
+        assert_eq!(MontgomeryPoint::mul_clamped_x4(&points, bytes), expected);
+    }
+
+    #[test]
+    #[cfg(feature = "pkcs8")]
+    fn pkcs8_spki_rfc8410_example() {
+        use pkcs8::DecodePublicKey;
+
+        // The X25519 public key of RFC 8410, section 10.1.
+        let der = hex::decode(
+            "302a300506032b656e03210019bf44096984cdfe8541bac167dc3b96c85086aa30b6b6cb0c5c38ad703166e1",
+        )
+        .unwrap();
+        let point = MontgomeryPoint::from_public_key_der(&der).unwrap();
+        assert_eq!(point.as_bytes()[..], der[12..]);
+
+        #[cfg(feature = "alloc")]
+        {
+            use pkcs8::EncodePublicKey;
+            assert_eq!(point.to_public_key_der().unwrap().as_bytes(), &der[..]);
+        }
+
+        // The same key with the Ed25519 OID is rejected.
+        let mut ed25519 = der;
+        ed25519[8] = 0x70;
+        assert!(MontgomeryPoint::from_public_key_der(&ed25519).is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "pkcs8", feature = "alloc"))]
+    fn pkcs8_secret_round_trip() {
+        let mut secret = [0u8; 32];
+        rand_core::OsRng.fill_bytes(&mut secret);
+
+        let der = secret_to_pkcs8_der(&secret).unwrap();
+        assert_eq!(secret_from_pkcs8_der(der.as_bytes()), Ok(secret));
+        let clamped = clamp_integer(secret);
+        let der = secret_to_pkcs8_der(&clamped).unwrap();
+        assert_eq!(secret_from_pkcs8_der(der.as_bytes()), Ok(clamped));
+
+        // A PKCS#8 v2 structure must hold the matching public key.
+        let private_key = [&[0x04, 0x20][..], &secret[..]].concat();
+        let public_key = MontgomeryPoint::mul_base_clamped(secret);
+        let mut info = pkcs8::PrivateKeyInfo::new(X25519_ALGORITHM_ID, &private_key);
+        info.public_key = Some(public_key.as_bytes());
+        let der = pkcs8::SecretDocument::encode_msg(&info).unwrap();
+        assert_eq!(secret_from_pkcs8_der(der.as_bytes()), Ok(secret));
+
+        let other = MontgomeryPoint::mul_base_clamped([1; 32]);
+        info.public_key = Some(other.as_bytes());
+        let der = pkcs8::SecretDocument::encode_msg(&info).unwrap();
+        assert!(secret_from_pkcs8_der(der.as_bytes()).is_err());
+    }
+
     #[cfg(feature = "alloc")]
     const ELLIGATOR_CORRECT_OUTPUT: [u8; 32] = [
         0x5f, 0x35, 0x20, 0x00, 0x1c, 0x6c, 0x99, 0x36, 0xa3, 0x12, 0x06, 0xaf, 0xe7, 0xc7, 0xac,
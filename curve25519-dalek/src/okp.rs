@@ -0,0 +1,535 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! COSE and JOSE encodings of X25519 and Ed25519 public keys.
+//!
+//! Both formats describe these keys as an "octet key pair" (OKP): a key
+//! type, a curve name, and the raw 32-byte public key `x`.
+//!
+//! * A [COSE_Key] ([RFC 9053], section 7.2) is a CBOR map
+//!   `{1: 1, -1: crv, -2: x}`, where `crv` is 4 for X25519 and 6 for
+//!   Ed25519.  This is the format used by FIDO2 and WebAuthn.
+//! * A [JWK] ([RFC 8037]) is a JSON object
+//!   `{"kty": "OKP", "crv": ..., "x": ...}`, where `x` is unpadded
+//!   base64url.  [`Jwk`] holds its members; with the `serde` feature it can
+//!   be read and written with any serde JSON library.
+//!
+//! A `MontgomeryPoint` is encoded as an X25519 key and a
+//! `CompressedEdwardsY` as an Ed25519 key.  Decoding checks the key type
+//! and curve, but like the other constructors of these types it does not
+//! check that the bytes are a valid point.
+//!
+//! ```
+//! use curve25519_dalek::constants::X25519_BASEPOINT;
+//! use curve25519_dalek::montgomery::MontgomeryPoint;
+//!
+//! let cose = X25519_BASEPOINT.to_cose_key();
+//! assert_eq!(MontgomeryPoint::from_cose_key(&cose), Ok(X25519_BASEPOINT));
+//!
+//! let jwk = X25519_BASEPOINT.to_jwk();
+//! assert_eq!(jwk.crv, "X25519");
+//! assert_eq!(MontgomeryPoint::from_jwk(&jwk), Ok(X25519_BASEPOINT));
+//! ```
+//!
+//! [COSE_Key]: https://www.rfc-editor.org/rfc/rfc9052#section-7
+//! [RFC 9053]: https://www.rfc-editor.org/rfc/rfc9053
+//! [JWK]: https://www.rfc-editor.org/rfc/rfc7517
+//! [RFC 8037]: https://www.rfc-editor.org/rfc/rfc8037
+
+use alloc::string::String;
+
+use core::fmt;
+
+use crate::edwards::CompressedEdwardsY;
+use crate::montgomery::MontgomeryPoint;
+
+/// An error decoding a COSE_Key or JWK.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum OkpError {
+    /// The input is not a well-formed COSE_Key, or is missing a member.
+    Malformed,
+    /// The key type is not OKP.
+    WrongKeyType,
+    /// The key is for a different curve.
+    WrongCurve,
+    /// The public key is not 32 bytes long.
+    InvalidLength,
+}
+
+impl fmt::Display for OkpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OkpError::Malformed => write!(f, "malformed key"),
+            OkpError::WrongKeyType => write!(f, "key type is not OKP"),
+            OkpError::WrongCurve => write!(f, "key is for a different curve"),
+            OkpError::InvalidLength => write!(f, "public key is not 32 bytes"),
+        }
+    }
+}
+
+/// The members of an OKP JSON Web Key holding a public key.
+///
+/// Other members, such as `kid` or `use`, are ignored when deserializing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Jwk {
+    /// The key type, `"OKP"`.
+    pub kty: String,
+    /// The curve, `"X25519"` or `"Ed25519"`.
+    pub crv: String,
+    /// The public key, as unpadded base64url.
+    pub x: String,
+}
+
+/// The curves of the OKP keys in this module.
+#[derive(Copy, Clone)]
+enum Curve {
+    X25519,
+    Ed25519,
+}
+
+impl Curve {
+    /// The identifier in the COSE Elliptic Curves registry.
+    fn cose_id(self) -> u8 {
+        match self {
+            Curve::X25519 => 4,
+            Curve::Ed25519 => 6,
+        }
+    }
+
+    /// The name in the JSON Web Key Elliptic Curve registry.
+    fn jwk_name(self) -> &'static str {
+        match self {
+            Curve::X25519 => "X25519",
+            Curve::Ed25519 => "Ed25519",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// COSE_Key
+// ------------------------------------------------------------------------
+
+/// The COSE_Key labels and values used here.
+const KTY: i128 = 1;
+const KTY_OKP: i128 = 1;
+const CRV: i128 = -1;
+const X: i128 = -2;
+
+/// How deeply nested the values we skip over may be.
+const MAX_DEPTH: usize = 16;
+
+/// Encode `x` as the deterministically encoded CBOR map
+/// `{1: 1, -1: crv, -2: x}`.
+fn to_cose_key(curve: Curve, x: &[u8; 32]) -> [u8; 40] {
+    let mut key = [0u8; 40];
+    key[..8].copy_from_slice(&[
+        0xa3, // map of 3 entries
+        0x01,
+        0x01, // kty: OKP
+        0x20,
+        curve.cose_id(), // crv
+        0x21,
+        0x58,
+        0x20, // x: 32-byte string
+    ]);
+    key[8..].copy_from_slice(x);
+    key
+}
+
+/// Decode the public key from a COSE_Key for `curve`.  Entries other than
+/// `kty`, `crv` and `x` are skipped.
+fn from_cose_key(curve: Curve, bytes: &[u8]) -> Result<[u8; 32], OkpError> {
+    let mut reader = CborReader { bytes };
+    let (major, entries) = reader.head()?;
+    if major != 5 {
+        return Err(OkpError::Malformed);
+    }
+
+    let (mut kty, mut crv, mut x) = (None, None, None);
+    for _ in 0..entries {
+        match reader.label()? {
+            Some(KTY) if kty.is_none() => kty = Some(reader.label()?),
+            Some(CRV) if crv.is_none() => crv = Some(reader.label()?),
+            Some(X) if x.is_none() => x = Some(reader.byte_string()?),
+            // Duplicate labels are not allowed.
+            Some(KTY) | Some(CRV) | Some(X) => return Err(OkpError::Malformed),
+            _ => reader.skip(0)?,
+        }
+    }
+    if !reader.bytes.is_empty() {
+        return Err(OkpError::Malformed);
+    }
+
+    match kty {
+        None => return Err(OkpError::Malformed),
+        Some(kty) if kty != Some(KTY_OKP) => return Err(OkpError::WrongKeyType),
+        _ => {}
+    }
+    match crv {
+        None => return Err(OkpError::Malformed),
+        Some(crv) if crv != Some(curve.cose_id() as i128) => return Err(OkpError::WrongCurve),
+        _ => {}
+    }
+    let x = x.ok_or(OkpError::Malformed)?;
+    x.try_into().map_err(|_| OkpError::InvalidLength)
+}
+
+/// A reader for the subset of CBOR which a COSE_Key uses: definite-length
+/// items only.
+struct CborReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> CborReader<'a> {
+    fn take(&mut self, n: u64) -> Result<&'a [u8], OkpError> {
+        if n > self.bytes.len() as u64 {
+            return Err(OkpError::Malformed);
+        }
+        let (taken, rest) = self.bytes.split_at(n as usize);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    /// Read the head of an item, returning its major type and argument.
+    fn head(&mut self) -> Result<(u8, u64), OkpError> {
+        let initial = self.take(1)?[0];
+        let len = match initial & 0x1f {
+            info @ 0..=23 => return Ok((initial >> 5, info as u64)),
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            // Reserved values and indefinite lengths
+            _ => return Err(OkpError::Malformed),
+        };
+        let arg = self
+            .take(len)?
+            .iter()
+            .fold(0u64, |arg, &b| (arg << 8) | b as u64);
+        Ok((initial >> 5, arg))
+    }
+
+    /// Read an integer or a text string, such as a map label, returning
+    /// the value of an integer.
+    fn label(&mut self) -> Result<Option<i128>, OkpError> {
+        match self.head()? {
+            (0, n) => Ok(Some(n as i128)),
+            (1, n) => Ok(Some(-1 - n as i128)),
+            (3, len) => self.take(len).map(|_| None),
+            _ => Err(OkpError::Malformed),
+        }
+    }
+
+    /// Read a byte string.
+    fn byte_string(&mut self) -> Result<&'a [u8], OkpError> {
+        match self.head()? {
+            (2, len) => self.take(len),
+            _ => Err(OkpError::Malformed),
+        }
+    }
+
+    /// Skip over one item.
+    fn skip(&mut self, depth: usize) -> Result<(), OkpError> {
+        if depth > MAX_DEPTH {
+            return Err(OkpError::Malformed);
+        }
+        match self.head()? {
+            (0, _) | (1, _) | (7, _) => Ok(()),
+            (2, len) | (3, len) => self.take(len).map(|_| ()),
+            (4, n) => (0..n).try_for_each(|_| self.skip(depth + 1)),
+            (5, n) => (0..n).try_for_each(|_| {
+                self.skip(depth + 1)?;
+                self.skip(depth + 1)
+            }),
+            (_, _) => self.skip(depth + 1), // tag
+        }
+    }
+}
+
+// ------------------------------------------------------------------------
+// JWK
+// ------------------------------------------------------------------------
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn to_jwk(curve: Curve, x: &[u8; 32]) -> Jwk {
+    let mut encoded = String::with_capacity(43);
+    for chunk in x.chunks(3) {
+        let mut buf = [0u8; 3];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        let n = u32::from_be_bytes([0, buf[0], buf[1], buf[2]]);
+        for i in 0..=chunk.len() {
+            encoded.push(BASE64URL[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+        }
+    }
+    Jwk {
+        kty: "OKP".into(),
+        crv: curve.jwk_name().into(),
+        x: encoded,
+    }
+}
+
+fn from_jwk(curve: Curve, jwk: &Jwk) -> Result<[u8; 32], OkpError> {
+    if jwk.kty != "OKP" {
+        return Err(OkpError::WrongKeyType);
+    }
+    if jwk.crv != curve.jwk_name() {
+        return Err(OkpError::WrongCurve);
+    }
+
+    // 32 bytes are 43 base64url digits, the last of which carries 4 bits.
+    let digits = jwk.x.as_bytes();
+    if digits.len() != 43 {
+        return Err(OkpError::InvalidLength);
+    }
+    let mut x = [0u8; 32];
+    for (chunk, out) in digits.chunks(4).zip(x.chunks_mut(3)) {
+        let mut n = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let value = BASE64URL
+                .iter()
+                .position(|&d| d == c)
+                .ok_or(OkpError::Malformed)?;
+            n |= (value as u32) << (18 - 6 * i);
+        }
+        let bytes = n.to_be_bytes();
+        out.copy_from_slice(&bytes[1..1 + out.len()]);
+        if chunk.len() < 4 && bytes[1 + out.len()] != 0 {
+            // Non-zero padding bits make the encoding non-canonical.
+            return Err(OkpError::Malformed);
+        }
+    }
+    Ok(x)
+}
+
+// ------------------------------------------------------------------------
+// Point types
+// ------------------------------------------------------------------------
+
+impl MontgomeryPoint {
+    /// Encode this point as an X25519 COSE_Key.
+    pub fn to_cose_key(&self) -> [u8; 40] {
+        to_cose_key(Curve::X25519, self.as_bytes())
+    }
+
+    /// Decode a point from an X25519 COSE_Key.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<MontgomeryPoint, OkpError> {
+        from_cose_key(Curve::X25519, bytes).map(MontgomeryPoint)
+    }
+
+    /// Encode this point as an X25519 JWK.
+    pub fn to_jwk(&self) -> Jwk {
+        to_jwk(Curve::X25519, self.as_bytes())
+    }
+
+    /// Decode a point from an X25519 JWK.
+    pub fn from_jwk(jwk: &Jwk) -> Result<MontgomeryPoint, OkpError> {
+        from_jwk(Curve::X25519, jwk).map(MontgomeryPoint)
+    }
+}
+
+impl CompressedEdwardsY {
+    /// Encode this point as an Ed25519 COSE_Key.
+    pub fn to_cose_key(&self) -> [u8; 40] {
+        to_cose_key(Curve::Ed25519, self.as_bytes())
+    }
+
+    /// Decode a point from an Ed25519 COSE_Key.
+    pub fn from_cose_key(bytes: &[u8]) -> Result<CompressedEdwardsY, OkpError> {
+        from_cose_key(Curve::Ed25519, bytes).map(CompressedEdwardsY)
+    }
+
+    /// Encode this point as an Ed25519 JWK.
+    pub fn to_jwk(&self) -> Jwk {
+        to_jwk(Curve::Ed25519, self.as_bytes())
+    }
+
+    /// Decode a point from an Ed25519 JWK.
+    pub fn from_jwk(jwk: &Jwk) -> Result<CompressedEdwardsY, OkpError> {
+        from_jwk(Curve::Ed25519, jwk).map(CompressedEdwardsY)
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Bob's public key from RFC 7748, section 6.1, used as the example
+    /// X25519 JWK in RFC 8037, appendix A.6.
+    const BOB: [u8; 32] = [
+        0xde, 0x9e, 0xdb, 0x7d, 0x7b, 0x7d, 0xc1, 0xb4, 0xd3, 0x5b, 0x61, 0xc2, 0xec, 0xe4, 0x35,
+        0x37, 0x3f, 0x83, 0x43, 0xc8, 0x5b, 0x78, 0x67, 0x4d, 0xad, 0xfc, 0x7e, 0x14, 0x6f, 0x88,
+        0x2b, 0x4f,
+    ];
+
+    /// The public key of RFC 8032, section 7.1, test 1, used as the example
+    /// Ed25519 JWK in RFC 8037, appendix A.2.
+    const ED25519_KEY: [u8; 32] = [
+        0xd7, 0x5a, 0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9, 0x64, 0x07,
+        0x3a, 0x0e, 0xe1, 0x72, 0xf3, 0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68, 0xf7, 0x07,
+        0x51, 0x1a,
+    ];
+
+    #[test]
+    fn jwk_rfc8037_examples() {
+        let jwk = MontgomeryPoint(BOB).to_jwk();
+        assert_eq!(jwk.kty, "OKP");
+        assert_eq!(jwk.crv, "X25519");
+        assert_eq!(jwk.x, "3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08");
+        assert_eq!(MontgomeryPoint::from_jwk(&jwk), Ok(MontgomeryPoint(BOB)));
+
+        let jwk = CompressedEdwardsY(ED25519_KEY).to_jwk();
+        assert_eq!(jwk.crv, "Ed25519");
+        assert_eq!(jwk.x, "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo");
+        assert_eq!(
+            CompressedEdwardsY::from_jwk(&jwk),
+            Ok(CompressedEdwardsY(ED25519_KEY))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn jwk_json() {
+        let json = r#"{"kty":"OKP","crv":"X25519","kid":"Bob",
+            "x":"3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08"}"#;
+        let jwk: Jwk = serde_json::from_str(json).unwrap();
+        assert_eq!(MontgomeryPoint::from_jwk(&jwk), Ok(MontgomeryPoint(BOB)));
+        assert_eq!(
+            serde_json::to_string(&jwk).unwrap(),
+            r#"{"kty":"OKP","crv":"X25519","x":"3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08"}"#
+        );
+    }
+
+    #[test]
+    fn jwk_rejects_invalid_keys() {
+        let jwk = MontgomeryPoint(BOB).to_jwk();
+        assert_eq!(
+            CompressedEdwardsY::from_jwk(&jwk),
+            Err(OkpError::WrongCurve)
+        );
+
+        let mut bad = jwk.clone();
+        bad.kty = "EC".into();
+        assert_eq!(MontgomeryPoint::from_jwk(&bad), Err(OkpError::WrongKeyType));
+
+        let mut bad = jwk.clone();
+        bad.x.push('A');
+        assert_eq!(
+            MontgomeryPoint::from_jwk(&bad),
+            Err(OkpError::InvalidLength)
+        );
+
+        let mut bad = jwk.clone();
+        bad.x.replace_range(..1, "+");
+        assert_eq!(MontgomeryPoint::from_jwk(&bad), Err(OkpError::Malformed));
+
+        // The last digit holds 4 bits of the key and 2 zero bits, so "9" is
+        // not a canonical encoding.
+        let mut bad = jwk;
+        bad.x.replace_range(42.., "9");
+        assert_eq!(MontgomeryPoint::from_jwk(&bad), Err(OkpError::Malformed));
+    }
+
+    #[test]
+    fn cose_key_round_trip() {
+        let cose = MontgomeryPoint(BOB).to_cose_key();
+        assert_eq!(cose[..8], [0xa3, 0x01, 0x01, 0x20, 0x04, 0x21, 0x58, 0x20]);
+        assert_eq!(cose[8..], BOB);
+        assert_eq!(
+            MontgomeryPoint::from_cose_key(&cose),
+            Ok(MontgomeryPoint(BOB))
+        );
+        assert_eq!(
+            CompressedEdwardsY::from_cose_key(&cose),
+            Err(OkpError::WrongCurve)
+        );
+
+        let cose = CompressedEdwardsY(ED25519_KEY).to_cose_key();
+        assert_eq!(cose[4], 0x06);
+        assert_eq!(
+            CompressedEdwardsY::from_cose_key(&cose),
+            Ok(CompressedEdwardsY(ED25519_KEY))
+        );
+    }
+
+    #[test]
+    fn cose_key_with_other_entries() {
+        // {3: -25, -2: x, 2: h'6b6964', -1: 4, "n": [1, {}], 1: 1}
+        let mut key = vec![0xa6, 0x03, 0x38, 0x18, 0x21, 0x58, 0x20];
+        key.extend_from_slice(&BOB);
+        key.extend_from_slice(&[0x02, 0x43, 0x6b, 0x69, 0x64, 0x20, 0x04]);
+        key.extend_from_slice(&[0x61, 0x6e, 0x82, 0x01, 0xa0, 0x01, 0x01]);
+        assert_eq!(
+            MontgomeryPoint::from_cose_key(&key),
+            Ok(MontgomeryPoint(BOB))
+        );
+
+        // Trailing bytes
+        key.push(0x00);
+        assert_eq!(
+            MontgomeryPoint::from_cose_key(&key),
+            Err(OkpError::Malformed)
+        );
+    }
+
+    #[test]
+    fn cose_key_rejects_invalid_keys() {
+        let cose = MontgomeryPoint(BOB).to_cose_key();
+
+        // Truncated
+        assert_eq!(
+            MontgomeryPoint::from_cose_key(&cose[..39]),
+            Err(OkpError::Malformed)
+        );
+
+        // kty: EC2
+        let mut bad = cose;
+        bad[2] = 0x02;
+        assert_eq!(
+            MontgomeryPoint::from_cose_key(&bad),
+            Err(OkpError::WrongKeyType)
+        );
+
+        // Duplicate kty instead of crv
+        let mut bad = cose;
+        bad[3] = 0x01;
+        assert_eq!(
+            MontgomeryPoint::from_cose_key(&bad),
+            Err(OkpError::Malformed)
+        );
+
+        // A 31-byte x
+        let mut bad = cose[..39].to_vec();
+        bad[7] = 0x1f;
+        assert_eq!(
+            MontgomeryPoint::from_cose_key(&bad),
+            Err(OkpError::InvalidLength)
+        );
+
+        // Missing x
+        let mut bad = cose[..5].to_vec();
+        bad[0] = 0xa2;
+        assert_eq!(
+            MontgomeryPoint::from_cose_key(&bad),
+            Err(OkpError::Malformed)
+        );
+
+        // Deeply nested arrays
+        let mut bad = vec![0xa4];
+        bad.extend_from_slice(&cose[1..]);
+        bad.extend_from_slice(&[0x02; 1]);
+        bad.extend_from_slice(&[0x81; 64]);
+        bad.push(0x00);
+        assert_eq!(
+            MontgomeryPoint::from_cose_key(&bad),
+            Err(OkpError::Malformed)
+        );
+    }
+}
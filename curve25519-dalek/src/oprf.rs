@@ -0,0 +1,191 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+#![allow(non_snake_case)]
+
+//! The group operations underlying an oblivious pseudorandom function
+//! over the Ristretto group.
+//!
+//! **This is a low-level building block**, not an implementation of the
+//! OPRF protocol of [RFC 9497]: it provides only the algebraic core, and
+//! leaves serialization, the final hash of the output, and the verifiable
+//! modes to the caller.
+//!
+//! The client maps its input \\(x\\) to a point and blinds it with a random
+//! scalar \\(r\\); the server evaluates the blinded point with its key
+//! \\(k\\); and the client removes the blind, learning \\(k H(x)\\) without
+//! the server learning \\(x\\):
+//! $$
+//! \begin{aligned}
+//! \mathrm{blinded} &= r H(x), \\\\
+//! \mathrm{evaluated} &= k \cdot \mathrm{blinded}, \\\\
+//! \mathrm{output} &= r\^{-1} \cdot \mathrm{evaluated}.
+//! \end{aligned}
+//! $$
+//!
+//! Every step runs in constant time and returns a `CtOption`, which is
+//! `None` whenever an input or the result would be the identity: a zero
+//! blind or key, an input hashing to the identity, or an identity point
+//! supplied by the other party.  Callers must treat `None` as a protocol
+//! error.
+//!
+//! # Example
+//!
+#![cfg_attr(all(feature = "digest", feature = "rand_core"), doc = "```")]
+#![cfg_attr(not(all(feature = "digest", feature = "rand_core")), doc = "```ignore")]
+//! # fn main() {
+//! use curve25519_dalek::oprf;
+//! use curve25519_dalek::scalar::Scalar;
+//! use rand_core::OsRng;
+//! use sha2::Sha512;
+//!
+//! let dst = b"HashToGroup-OPRFV1-\x00-ristretto255-SHA512";
+//! let k = Scalar::random(&mut OsRng);
+//!
+//! // Client
+//! let r = Scalar::random(&mut OsRng);
+//! let blinded = oprf::blind::<Sha512>(&r, dst, b"input").unwrap();
+//! // Server
+//! let evaluated = oprf::evaluate(&k, &blinded).unwrap();
+//! // Client
+//! let output = oprf::unblind(&r, &evaluated).unwrap();
+//!
+//! assert_eq!(output, oprf::evaluate_unblinded::<Sha512>(&k, dst, b"input").unwrap());
+//! # }
+//! ```
+//!
+//! [RFC 9497]: https://www.rfc-editor.org/rfc/rfc9497.html
+
+#[cfg(feature = "digest")]
+use digest::crypto_common::BlockSizeUser;
+#[cfg(feature = "digest")]
+use digest::generic_array::typenum::U64;
+#[cfg(feature = "digest")]
+use digest::Digest;
+
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+use crate::ristretto::RistrettoPoint;
+use crate::scalar::Scalar;
+use crate::traits::Identity;
+
+/// Return `Choice(1)` if the scalar `s` is zero.
+fn is_zero(s: &Scalar) -> Choice {
+    s.ct_eq(&Scalar::ZERO)
+}
+
+/// Return `Choice(1)` if the point `P` is the identity.
+fn is_identity(P: &RistrettoPoint) -> Choice {
+    P.ct_eq(&RistrettoPoint::identity())
+}
+
+/// Hash `input` to the group under the domain separation tag `dst`, and
+/// blind it with the scalar `r`.
+///
+/// The hash to the group is [`RistrettoPoint::hash_with_dst`].  The blind
+/// `r` must be chosen uniformly at random for each evaluation, and kept
+/// for [`unblind`].
+///
+/// Returns `None` if `r` is zero or `input` hashes to the identity.
+///
+/// # Panics
+///
+/// Panics if `dst` is empty.
+#[cfg(feature = "digest")]
+pub fn blind<D>(r: &Scalar, dst: &[u8], input: &[u8]) -> CtOption<RistrettoPoint>
+where
+    D: Digest<OutputSize = U64> + BlockSizeUser,
+{
+    let P = RistrettoPoint::hash_with_dst::<D>(dst, input);
+    CtOption::new(r * P, !(is_zero(r) | is_identity(&P)))
+}
+
+/// Evaluate the `blinded` point received from a client with the key `k`.
+///
+/// Returns `None` if `k` is zero or `blinded` is the identity.
+pub fn evaluate(k: &Scalar, blinded: &RistrettoPoint) -> CtOption<RistrettoPoint> {
+    CtOption::new(k * blinded, !(is_zero(k) | is_identity(blinded)))
+}
+
+/// Remove the blind `r` from the `evaluated` point received from the
+/// server.
+///
+/// Returns `None` if `r` is zero or `evaluated` is the identity.
+pub fn unblind(r: &Scalar, evaluated: &RistrettoPoint) -> CtOption<RistrettoPoint> {
+    CtOption::new(
+        r.invert() * evaluated,
+        !(is_zero(r) | is_identity(evaluated)),
+    )
+}
+
+/// Compute the unblinded evaluation \\(k H(x)\\) directly, as a server
+/// holding both the key and the input would.
+///
+/// Returns `None` if `k` is zero or `input` hashes to the identity.
+///
+/// # Panics
+///
+/// Panics if `dst` is empty.
+#[cfg(feature = "digest")]
+pub fn evaluate_unblinded<D>(k: &Scalar, dst: &[u8], input: &[u8]) -> CtOption<RistrettoPoint>
+where
+    D: Digest<OutputSize = U64> + BlockSizeUser,
+{
+    evaluate(k, &RistrettoPoint::hash_with_dst::<D>(dst, input))
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "digest"))]
+mod test {
+    use super::*;
+    use rand_core::OsRng;
+    use sha2::Sha512;
+
+    const DST: &[u8] = b"curve25519-dalek oprf test";
+
+    #[test]
+    fn blind_evaluate_unblind() {
+        let k = Scalar::random(&mut OsRng);
+        let r = Scalar::random(&mut OsRng);
+
+        let blinded = blind::<Sha512>(&r, DST, b"input").unwrap();
+        let evaluated = evaluate(&k, &blinded).unwrap();
+        let output = unblind(&r, &evaluated).unwrap();
+
+        assert_eq!(
+            output,
+            evaluate_unblinded::<Sha512>(&k, DST, b"input").unwrap()
+        );
+        assert_ne!(
+            output,
+            evaluate_unblinded::<Sha512>(&k, DST, b"other").unwrap()
+        );
+
+        // The blinded point reveals nothing that depends only on the input.
+        let r2 = Scalar::random(&mut OsRng);
+        assert_ne!(blinded, blind::<Sha512>(&r2, DST, b"input").unwrap());
+    }
+
+    #[test]
+    fn identity_and_zero_are_rejected() {
+        let k = Scalar::random(&mut OsRng);
+        let P = RistrettoPoint::random(&mut OsRng);
+        let O = RistrettoPoint::identity();
+
+        assert!(bool::from(
+            blind::<Sha512>(&Scalar::ZERO, DST, b"input").is_none()
+        ));
+        assert!(bool::from(evaluate(&Scalar::ZERO, &P).is_none()));
+        assert!(bool::from(evaluate(&k, &O).is_none()));
+        assert!(bool::from(unblind(&Scalar::ZERO, &P).is_none()));
+        assert!(bool::from(unblind(&k, &O).is_none()));
+        assert!(bool::from(
+            evaluate_unblinded::<Sha512>(&Scalar::ZERO, DST, b"input").is_none()
+        ));
+    }
+}
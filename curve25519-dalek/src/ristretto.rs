@@ -67,6 +67,8 @@
 //! The encoding is not batchable, but it is possible to
 //! double-and-encode in a batch using
 //! `RistrettoPoint::double_and_compress_batch`.
+//! `RistrettoPoint::compress_batch` encodes a batch of points as-is,
+//! computing the inverse square roots four at a time.
 //!
 //! ## Equality Testing
 //!
@@ -117,9 +119,16 @@
 //!
 //! * `RistrettoPoint::from_hash()` and
 //! `RistrettoPoint::hash_from_bytes()`, which perform hashing to the
-//! group.
+//! group;
 //!
-//! The Elligator map itself is not currently exposed.
+//! * `GeneratorsChain`, which derives a sequence of \\(2\\^{64}\\)
+//!   independent generators from a label, and `GeneratorsCache`, which
+//!   keeps the generators it has already derived.
+//!
+//! The Elligator map itself is not currently exposed, but it can be
+//! inverted: `RistrettoPoint::to_uniform_representative()` and
+//! `RistrettoPoint::from_uniform_representative()` encode points as
+//! strings indistinguishable from uniformly random bytes.
 //!
 //! ## Implementation
 //!
@@ -172,32 +181,40 @@ use core::ops::{Mul, MulAssign};
 #[cfg(any(test, feature = "rand_core"))]
 use rand_core::CryptoRngCore;
 
+#[cfg(feature = "digest")]
+use digest::crypto_common::BlockSizeUser;
 #[cfg(feature = "digest")]
 use digest::generic_array::typenum::U64;
 #[cfg(feature = "digest")]
 use digest::Digest;
 
+use cfg_if::cfg_if;
+
 use crate::constants;
+use crate::encoding::ParseHexError;
 use crate::field::FieldElement;
 
 #[cfg(feature = "group")]
 use {
     group::{cofactor::CofactorGroup, prime::PrimeGroup, GroupEncoding},
     rand_core::RngCore,
-    subtle::CtOption,
 };
 
 use subtle::Choice;
 use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
+use subtle::CtOption;
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
-#[cfg(feature = "precomputed-tables")]
-use crate::edwards::EdwardsBasepointTable;
 use crate::edwards::EdwardsPoint;
+#[cfg(feature = "precomputed-tables")]
+use crate::edwards::{
+    EdwardsBasepointTable, EdwardsBasepointTableRadix128, EdwardsBasepointTableRadix256,
+    EdwardsBasepointTableRadix32, EdwardsBasepointTableRadix64,
+};
 
 use crate::scalar::Scalar;
 
@@ -205,7 +222,10 @@ use crate::scalar::Scalar;
 use crate::traits::BasepointTable;
 use crate::traits::Identity;
 #[cfg(feature = "alloc")]
-use crate::traits::{MultiscalarMul, VartimeMultiscalarMul, VartimePrecomputedMultiscalarMul};
+use crate::traits::{
+    MultiscalarMul, MultiscalarMulAlgorithm, VartimeMultiscalarMul,
+    VartimePrecomputedMultiscalarMul,
+};
 
 // ------------------------------------------------------------------------
 // Compressed points
@@ -216,6 +236,7 @@ use crate::traits::{MultiscalarMul, VartimeMultiscalarMul, VartimePrecomputedMul
 /// The Ristretto encoding is canonical, so two points are equal if and
 /// only if their encodings are equal.
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
 pub struct CompressedRistretto(pub [u8; 32]);
 
 impl ConstantTimeEq for CompressedRistretto {
@@ -224,6 +245,24 @@ impl ConstantTimeEq for CompressedRistretto {
     }
 }
 
+/// Compares encodings byte-lexicographically, in variable time.
+///
+/// This is meant for sorting and deduplicating public values, e.g. keys in
+/// a `BTreeMap`; it must not be used on secret data.  The order has no
+/// meaning in the group.
+impl PartialOrd for CompressedRistretto {
+    fn partial_cmp(&self, other: &CompressedRistretto) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// See the `PartialOrd` implementation: this is variable-time.
+impl Ord for CompressedRistretto {
+    fn cmp(&self, other: &CompressedRistretto) -> core::cmp::Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
 impl CompressedRistretto {
     /// Copy the bytes of this `CompressedRistretto`.
     pub const fn to_bytes(&self) -> [u8; 32] {
@@ -267,6 +306,102 @@ impl CompressedRistretto {
             Some(res)
         }
     }
+
+    /// Attempt to decompress four points at once.
+    ///
+    /// This returns the same results as calling
+    /// [`decompress`](CompressedRistretto::decompress) on each point, but on
+    /// CPUs with AVX2 or AVX512 IFMA the inverse square root computations
+    /// for all four points run in parallel vector lanes.
+    pub fn decompress_x4(compressed: &[CompressedRistretto; 4]) -> [Option<RistrettoPoint>; 4] {
+        let steps = decompress::step_2_x4(&compressed.map(|c| decompress::step_1(&c)));
+        [0, 1, 2, 3].map(|i| {
+            let (is_valid, res) = steps[i];
+            if is_valid.into() {
+                Some(res)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Attempt to decompress a slice of points.
+    ///
+    /// This returns the same results as calling
+    /// [`decompress`](CompressedRistretto::decompress) on each point, but
+    /// processes them four at a time with
+    /// [`decompress_x4`](CompressedRistretto::decompress_x4).
+    #[cfg(feature = "alloc")]
+    pub fn decompress_batch(compressed: &[CompressedRistretto]) -> Vec<Option<RistrettoPoint>> {
+        let mut points = Vec::with_capacity(compressed.len());
+        let mut chunks = compressed.chunks_exact(4);
+        for chunk in &mut chunks {
+            let chunk = [chunk[0], chunk[1], chunk[2], chunk[3]];
+            points.extend_from_slice(&CompressedRistretto::decompress_x4(&chunk));
+        }
+        points.extend(chunks.remainder().iter().map(|c| c.decompress()));
+        points
+    }
+}
+
+mod elligator {
+    use super::*;
+
+    /// Compute \\(r = i r\_0\^2\\) and the numerator \\(N\_s\\) and
+    /// denominator \\(D\\) of the ratio whose square root is taken.
+    #[allow(non_snake_case)]
+    pub(super) fn step_1(r_0: &FieldElement) -> (FieldElement, FieldElement, FieldElement) {
+        let i = &constants::SQRT_M1;
+        let d = &constants::EDWARDS_D;
+        let one_minus_d_sq = &constants::ONE_MINUS_EDWARDS_D_SQUARED;
+        let c = constants::MINUS_ONE;
+
+        let one = FieldElement::ONE;
+
+        let r = i * &r_0.square();
+        let N_s = &(&r + &one) * one_minus_d_sq;
+        let D = &(&c - &(d * &r)) * &(&r + d);
+
+        (r, N_s, D)
+    }
+
+    /// Finish the map, given the result of `sqrt_ratio_i(N_s, D)`.
+    #[allow(non_snake_case)]
+    pub(super) fn step_2(
+        r_0: &FieldElement,
+        r: &FieldElement,
+        D: &FieldElement,
+        Ns_D_is_sq: Choice,
+        mut s: FieldElement,
+    ) -> RistrettoPoint {
+        let d_minus_one_sq = &constants::EDWARDS_D_MINUS_ONE_SQUARED;
+        let mut c = constants::MINUS_ONE;
+
+        let one = FieldElement::ONE;
+
+        let mut s_prime = &s * r_0;
+        let s_prime_is_pos = !s_prime.is_negative();
+        s_prime.conditional_negate(s_prime_is_pos);
+
+        s.conditional_assign(&s_prime, !Ns_D_is_sq);
+        c.conditional_assign(r, !Ns_D_is_sq);
+
+        let N_t = &(&(&c * &(r - &one)) * d_minus_one_sq) - D;
+        let s_sq = s.square();
+
+        use crate::backend::serial::curve_models::CompletedPoint;
+
+        // The conversion from W_i is exactly the conversion from P1xP1.
+        RistrettoPoint(
+            CompletedPoint {
+                X: &(&s + &s) * D,
+                Z: &N_t * &constants::SQRT_AD_MINUS_ONE,
+                Y: &FieldElement::ONE - &s_sq,
+                T: &FieldElement::ONE + &s_sq,
+            }
+            .as_extended(),
+        )
+    }
 }
 
 mod decompress {
@@ -293,6 +428,37 @@ mod decompress {
     }
 
     pub(super) fn step_2(s: FieldElement) -> (Choice, Choice, Choice, RistrettoPoint) {
+        let (u1, u2, v, u2_sqr) = step_2_denominators(&s);
+        let (ok, I) = (&v * &u2_sqr).invsqrt(); // 1/sqrt(v*u_2²)
+        step_2_finish(s, &u1, &u2, &v, ok, &I)
+    }
+
+    /// Perform `step_1` and `step_2` for four points, with the inverse
+    /// square roots computed by the selected backend.  Returns whether each
+    /// encoding is valid, and the decoded point.
+    pub(super) fn step_2_x4(
+        steps: &[(Choice, Choice, FieldElement); 4],
+    ) -> [(Choice, RistrettoPoint); 4] {
+        let denominators = steps.map(|(_, _, s)| step_2_denominators(&s));
+        let v_u2_sqr = denominators.map(|(_, _, v, u2_sqr)| &v * &u2_sqr);
+        let roots = crate::backend::sqrt_ratio_i_x4(&[FieldElement::ONE; 4], &v_u2_sqr);
+
+        [0, 1, 2, 3].map(|i| {
+            let (s_encoding_is_canonical, s_is_negative, s) = steps[i];
+            let (u1, u2, v, _) = &denominators[i];
+            let (ok, I) = roots[i];
+            let (ok, t_is_negative, y_is_zero, res) = step_2_finish(s, u1, u2, v, ok, &I);
+            let is_valid =
+                s_encoding_is_canonical & !s_is_negative & ok & !t_is_negative & !y_is_zero;
+            (is_valid, res)
+        })
+    }
+
+    /// Compute \\(u\_1 = 1 + as\^2\\), \\(u\_2 = 1 - as\^2\\),
+    /// \\(v = ad u\_1\^2 - u\_2\^2\\) and \\(u\_2\^2\\).
+    fn step_2_denominators(
+        s: &FieldElement,
+    ) -> (FieldElement, FieldElement, FieldElement, FieldElement) {
         // Step 2.  Compute (X:Y:Z:T).
         let one = FieldElement::ONE;
         let ss = s.square();
@@ -303,10 +469,22 @@ mod decompress {
         // v == ad(1+as²)² - (1-as²)²            where d=-121665/121666
         let v = &(&(-&constants::EDWARDS_D) * &u1.square()) - &u2_sqr;
 
-        let (ok, I) = (&v * &u2_sqr).invsqrt(); // 1/sqrt(v*u_2²)
+        (u1, u2, v, u2_sqr)
+    }
 
-        let Dx = &I * &u2; // 1/sqrt(v)
-        let Dy = &I * &(&Dx * &v); // 1/u2
+    /// Finish `step_2`, given `I` = 1/sqrt(v*u_2²).
+    fn step_2_finish(
+        s: FieldElement,
+        u1: &FieldElement,
+        u2: &FieldElement,
+        v: &FieldElement,
+        ok: Choice,
+        I: &FieldElement,
+    ) -> (Choice, Choice, Choice, RistrettoPoint) {
+        let one = FieldElement::ONE;
+
+        let Dx = I * u2; // 1/sqrt(v)
+        let Dy = I * &(&Dx * v); // 1/u2
 
         // x == | 2s/sqrt(v) | == + sqrt(4s²/(ad(1+as²)² - (1-as²)²))
         let mut x = &(&s + &s) * &Dx;
@@ -314,7 +492,7 @@ mod decompress {
         x.conditional_negate(x_neg);
 
         // y == (1-as²)/(1+as²)
-        let y = &u1 * &Dy;
+        let y = u1 * &Dy;
 
         // t == ((1+as²) sqrt(4s²/(ad(1+as²)² - (1-as²)²)))/(1-as²)
         let t = &x * &y;
@@ -345,6 +523,14 @@ impl Default for CompressedRistretto {
     }
 }
 
+// SAFETY: `CompressedRistretto` is a `#[repr(transparent)]` `[u8; 32]` with
+// no invalid bit patterns; decompression does the validation.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for CompressedRistretto {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for CompressedRistretto {}
+
 impl TryFrom<&[u8]> for CompressedRistretto {
     type Error = TryFromSliceError;
 
@@ -361,8 +547,6 @@ impl TryFrom<&[u8]> for CompressedRistretto {
 // structs containing `RistrettoPoint`s and use Serde's derived
 // serializers to serialize those structures.
 
-#[cfg(feature = "serde")]
-use serde::de::Visitor;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -372,12 +556,7 @@ impl Serialize for RistrettoPoint {
     where
         S: Serializer,
     {
-        use serde::ser::SerializeTuple;
-        let mut tup = serializer.serialize_tuple(32)?;
-        for byte in self.compress().as_bytes().iter() {
-            tup.serialize_element(byte)?;
-        }
-        tup.end()
+        crate::encoding::serialize_bytes(self.compress().as_bytes(), serializer)
     }
 }
 
@@ -387,12 +566,7 @@ impl Serialize for CompressedRistretto {
     where
         S: Serializer,
     {
-        use serde::ser::SerializeTuple;
-        let mut tup = serializer.serialize_tuple(32)?;
-        for byte in self.as_bytes().iter() {
-            tup.serialize_element(byte)?;
-        }
-        tup.end()
+        crate::encoding::serialize_bytes(self.as_bytes(), serializer)
     }
 }
 
@@ -402,33 +576,10 @@ impl<'de> Deserialize<'de> for RistrettoPoint {
     where
         D: Deserializer<'de>,
     {
-        struct RistrettoPointVisitor;
-
-        impl<'de> Visitor<'de> for RistrettoPointVisitor {
-            type Value = RistrettoPoint;
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str("a valid point in Ristretto format")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<RistrettoPoint, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>,
-            {
-                let mut bytes = [0u8; 32];
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..32 {
-                    bytes[i] = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
-                }
-                CompressedRistretto(bytes)
-                    .decompress()
-                    .ok_or_else(|| serde::de::Error::custom("decompression failed"))
-            }
-        }
-
-        deserializer.deserialize_tuple(32, RistrettoPointVisitor)
+        let bytes = crate::encoding::deserialize_bytes(deserializer)?;
+        CompressedRistretto(bytes)
+            .decompress()
+            .ok_or_else(|| serde::de::Error::custom("decompression failed"))
     }
 }
 
@@ -438,31 +589,35 @@ impl<'de> Deserialize<'de> for CompressedRistretto {
     where
         D: Deserializer<'de>,
     {
-        struct CompressedRistrettoVisitor;
+        let bytes = crate::encoding::deserialize_bytes(deserializer)?;
+        Ok(CompressedRistretto(bytes))
+    }
+}
 
-        impl<'de> Visitor<'de> for CompressedRistrettoVisitor {
-            type Value = CompressedRistretto;
+// A `RistrettoBasepointTable` is serialized as the compressed encoding of
+// its basepoint, and the table is rebuilt on deserialization.  Accepting
+// the precomputed multiples themselves from the wire would let whoever
+// produced them choose the result of every scalar multiplication with the
+// table, and checking them is as expensive as recomputing them.
 
-            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str("32 bytes of data")
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<CompressedRistretto, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>,
-            {
-                let mut bytes = [0u8; 32];
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..32 {
-                    bytes[i] = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
-                }
-                Ok(CompressedRistretto(bytes))
-            }
-        }
+#[cfg(all(feature = "serde", feature = "precomputed-tables"))]
+impl Serialize for RistrettoBasepointTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.basepoint().serialize(serializer)
+    }
+}
 
-        deserializer.deserialize_tuple(32, CompressedRistrettoVisitor)
+#[cfg(all(feature = "serde", feature = "precomputed-tables"))]
+impl<'de> Deserialize<'de> for RistrettoBasepointTable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let basepoint = RistrettoPoint::deserialize(deserializer)?;
+        Ok(RistrettoBasepointTable::create(&basepoint))
     }
 }
 
@@ -484,20 +639,110 @@ impl<'de> Deserialize<'de> for CompressedRistretto {
 #[derive(Copy, Clone)]
 pub struct RistrettoPoint(pub(crate) EdwardsPoint);
 
+/// The intermediate values of `RistrettoPoint::double_and_compress_batch`
+/// for a single point, before the batched inversion.
+#[derive(Copy, Clone, Debug)]
+struct BatchCompressState {
+    e: FieldElement,
+    f: FieldElement,
+    g: FieldElement,
+    h: FieldElement,
+    eg: FieldElement,
+    fh: FieldElement,
+}
+
+impl BatchCompressState {
+    fn efgh(&self) -> FieldElement {
+        &self.eg * &self.fh
+    }
+
+    /// Finish compressing the doubled point, given the inverse of `efgh()`.
+    fn compress(&self, inv: &FieldElement) -> CompressedRistretto {
+        let Zinv = &self.eg * inv;
+        let Tinv = &self.fh * inv;
+
+        let mut magic = constants::INVSQRT_A_MINUS_D;
+
+        let negcheck1 = (&self.eg * &Zinv).is_negative();
+
+        let mut e = self.e;
+        let mut g = self.g;
+        let mut h = self.h;
+
+        let minus_e = -&e;
+        let f_times_sqrta = &self.f * &constants::SQRT_M1;
+
+        e.conditional_assign(&self.g, negcheck1);
+        g.conditional_assign(&minus_e, negcheck1);
+        h.conditional_assign(&f_times_sqrta, negcheck1);
+
+        magic.conditional_assign(&constants::SQRT_M1, negcheck1);
+
+        let negcheck2 = (&(&h * &e) * &Zinv).is_negative();
+
+        g.conditional_negate(negcheck2);
+
+        let mut s = &(&h - &g) * &(&magic * &(&g * &Tinv));
+
+        let s_is_negative = s.is_negative();
+        s.conditional_negate(s_is_negative);
+
+        CompressedRistretto(s.as_bytes())
+    }
+}
+
+impl<'a> From<&'a RistrettoPoint> for BatchCompressState {
+    #[rustfmt::skip] // keep alignment of explanatory comments
+    fn from(P: &'a RistrettoPoint) -> BatchCompressState {
+        let XX = P.0.X.square();
+        let YY = P.0.Y.square();
+        let ZZ = P.0.Z.square();
+        let dTT = &P.0.T.square() * &constants::EDWARDS_D;
+
+        let e = &P.0.X * &(&P.0.Y + &P.0.Y); // = 2*X*Y
+        let f = &ZZ + &dTT;                  // = Z^2 + d*T^2
+        let g = &YY + &XX;                   // = Y^2 - a*X^2
+        let h = &ZZ - &dTT;                  // = Z^2 - d*T^2
+
+        let eg = &e * &g;
+        let fh = &f * &h;
+
+        BatchCompressState{ e, f, g, h, eg, fh }
+    }
+}
+
 impl RistrettoPoint {
     /// Compress this point using the Ristretto encoding.
     pub fn compress(&self) -> CompressedRistretto {
+        let (u1, u2) = self.compress_denominators();
+        // Ignore return value since this is always square
+        let (_, invsqrt) = (&u1 * &u2.square()).invsqrt();
+        self.compress_finish(&u1, &u2, &invsqrt)
+    }
+
+    /// Compute \\(u\_1 = (Z+Y)(Z-Y)\\) and \\(u\_2 = XY\\), whose
+    /// product \\(u\_1 u\_2\^2\\) is always square.
+    fn compress_denominators(&self) -> (FieldElement, FieldElement) {
+        let u1 = &(&self.0.Z + &self.0.Y) * &(&self.0.Z - &self.0.Y);
+        let u2 = &self.0.X * &self.0.Y;
+        (u1, u2)
+    }
+
+    /// Finish the encoding, given the inverse square root of
+    /// \\(u\_1 u\_2\^2\\).
+    fn compress_finish(
+        &self,
+        u1: &FieldElement,
+        u2: &FieldElement,
+        invsqrt: &FieldElement,
+    ) -> CompressedRistretto {
         let mut X = self.0.X;
         let mut Y = self.0.Y;
         let Z = &self.0.Z;
         let T = &self.0.T;
 
-        let u1 = &(Z + &Y) * &(Z - &Y);
-        let u2 = &X * &Y;
-        // Ignore return value since this is always square
-        let (_, invsqrt) = (&u1 * &u2.square()).invsqrt();
-        let i1 = &invsqrt * &u1;
-        let i2 = &invsqrt * &u2;
+        let i1 = invsqrt * u1;
+        let i2 = invsqrt * u2;
         let z_inv = &i1 * &(&i2 * T);
         let mut den_inv = i2;
 
@@ -521,6 +766,68 @@ impl RistrettoPoint {
         CompressedRistretto(s.as_bytes())
     }
 
+    /// Compress four points at once.
+    ///
+    /// This returns the same results as calling
+    /// [`compress`](RistrettoPoint::compress) on each point, but on CPUs
+    /// with AVX2 or AVX512 IFMA the inverse square roots for all four
+    /// points run in parallel vector lanes.
+    #[cfg(feature = "alloc")]
+    fn compress_x4(points: [&RistrettoPoint; 4]) -> [CompressedRistretto; 4] {
+        let denominators = points.map(RistrettoPoint::compress_denominators);
+        let v = denominators.map(|(u1, u2)| &u1 * &u2.square());
+        let roots = crate::backend::sqrt_ratio_i_x4(&[FieldElement::ONE; 4], &v);
+        [0, 1, 2, 3].map(|i| {
+            let (u1, u2) = &denominators[i];
+            points[i].compress_finish(u1, u2, &roots[i].1)
+        })
+    }
+
+    /// Compress a batch of points, without altering them.
+    ///
+    /// This returns the same results as calling
+    /// [`RistrettoPoint::compress`] on each point, but computes the inverse
+    /// square roots four at a time, in parallel vector lanes on CPUs with
+    /// AVX2 or AVX512 IFMA.  Each point still needs its own inverse square
+    /// root, while the encoding of \\(\[2\]P\\) only needs an inversion,
+    /// which is batchable: when the caller is free to choose which multiple
+    /// of its points it serializes, [`RistrettoPoint::double_and_compress_batch`]
+    /// is much faster.
+    ///
+    #[cfg_attr(feature = "rand_core", doc = "```")]
+    #[cfg_attr(not(feature = "rand_core"), doc = "```ignore")]
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use rand_core::OsRng;
+    ///
+    /// # fn main() {
+    /// let mut rng = OsRng;
+    ///
+    /// let points: Vec<RistrettoPoint> =
+    ///     (0..32).map(|_| RistrettoPoint::random(&mut rng)).collect();
+    ///
+    /// let compressed = RistrettoPoint::compress_batch(&points);
+    ///
+    /// for (P, P_compressed) in points.iter().zip(compressed.iter()) {
+    ///     assert_eq!(*P_compressed, P.compress());
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn compress_batch<'a, I>(points: I) -> Vec<CompressedRistretto>
+    where
+        I: IntoIterator<Item = &'a RistrettoPoint>,
+    {
+        let points: Vec<&RistrettoPoint> = points.into_iter().collect();
+        let mut compressed = Vec::with_capacity(points.len());
+        let mut chunks = points.chunks_exact(4);
+        for chunk in &mut chunks {
+            let chunk = [chunk[0], chunk[1], chunk[2], chunk[3]];
+            compressed.extend_from_slice(&RistrettoPoint::compress_x4(chunk));
+        }
+        compressed.extend(chunks.remainder().iter().map(|P| P.compress()));
+        compressed
+    }
+
     /// Double-and-compress a batch of points.  The Ristretto encoding
     /// is not batchable, since it requires an inverse square root.
     ///
@@ -554,42 +861,6 @@ impl RistrettoPoint {
     where
         I: IntoIterator<Item = &'a RistrettoPoint>,
     {
-        #[derive(Copy, Clone, Debug)]
-        struct BatchCompressState {
-            e: FieldElement,
-            f: FieldElement,
-            g: FieldElement,
-            h: FieldElement,
-            eg: FieldElement,
-            fh: FieldElement,
-        }
-
-        impl BatchCompressState {
-            fn efgh(&self) -> FieldElement {
-                &self.eg * &self.fh
-            }
-        }
-
-        impl<'a> From<&'a RistrettoPoint> for BatchCompressState {
-            #[rustfmt::skip] // keep alignment of explanatory comments
-            fn from(P: &'a RistrettoPoint) -> BatchCompressState {
-                let XX = P.0.X.square();
-                let YY = P.0.Y.square();
-                let ZZ = P.0.Z.square();
-                let dTT = &P.0.T.square() * &constants::EDWARDS_D;
-
-                let e = &P.0.X * &(&P.0.Y + &P.0.Y); // = 2*X*Y
-                let f = &ZZ + &dTT;                  // = Z^2 + d*T^2
-                let g = &YY + &XX;                   // = Y^2 - a*X^2
-                let h = &ZZ - &dTT;                  // = Z^2 - d*T^2
-
-                let eg = &e * &g;
-                let fh = &f * &h;
-
-                BatchCompressState{ e, f, g, h, eg, fh }
-            }
-        }
-
         let states: Vec<BatchCompressState> =
             points.into_iter().map(BatchCompressState::from).collect();
 
@@ -600,39 +871,52 @@ impl RistrettoPoint {
         states
             .iter()
             .zip(invs.iter())
-            .map(|(state, inv): (&BatchCompressState, &FieldElement)| {
-                let Zinv = &state.eg * inv;
-                let Tinv = &state.fh * inv;
-
-                let mut magic = constants::INVSQRT_A_MINUS_D;
-
-                let negcheck1 = (&state.eg * &Zinv).is_negative();
-
-                let mut e = state.e;
-                let mut g = state.g;
-                let mut h = state.h;
-
-                let minus_e = -&e;
-                let f_times_sqrta = &state.f * &constants::SQRT_M1;
-
-                e.conditional_assign(&state.g, negcheck1);
-                g.conditional_assign(&minus_e, negcheck1);
-                h.conditional_assign(&f_times_sqrta, negcheck1);
-
-                magic.conditional_assign(&constants::SQRT_M1, negcheck1);
-
-                let negcheck2 = (&(&h * &e) * &Zinv).is_negative();
-
-                g.conditional_negate(negcheck2);
+            .map(|(state, inv)| state.compress(inv))
+            .collect()
+    }
 
-                let mut s = &(&h - &g) * &(&magic * &(&g * &Tinv));
+    /// Double-and-compress a fixed-size batch of points, without
+    /// allocating.
+    ///
+    /// This computes the same encodings as
+    /// [`RistrettoPoint::double_and_compress_batch`], writing
+    /// \\( \mathrm{enc}( \[2\]P\_i ) \\) to `out[i]`, but keeps all of its
+    /// scratch space on the stack, so it is available without the `alloc`
+    /// feature.
+    ///
+    #[cfg_attr(feature = "rand_core", doc = "```")]
+    #[cfg_attr(not(feature = "rand_core"), doc = "```ignore")]
+    /// # use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
+    /// use rand_core::OsRng;
+    ///
+    /// # fn main() {
+    /// let points = [(); 8].map(|_| RistrettoPoint::random(&mut OsRng));
+    /// let mut compressed = [CompressedRistretto::default(); 8];
+    ///
+    /// RistrettoPoint::double_and_compress_into(&points, &mut compressed);
+    ///
+    /// for (P, P2_compressed) in points.iter().zip(compressed.iter()) {
+    ///     assert_eq!(*P2_compressed, (P + P).compress());
+    /// }
+    /// # }
+    /// ```
+    pub fn double_and_compress_into<const N: usize>(
+        points: &[RistrettoPoint; N],
+        out: &mut [CompressedRistretto; N],
+    ) {
+        let mut states = [BatchCompressState::from(&RistrettoPoint::identity()); N];
+        let mut invs = [FieldElement::ONE; N];
+
+        for ((state, inv), P) in states.iter_mut().zip(invs.iter_mut()).zip(points) {
+            *state = BatchCompressState::from(P);
+            *inv = state.efgh();
+        }
 
-                let s_is_negative = s.is_negative();
-                s.conditional_negate(s_is_negative);
+        FieldElement::batch_invert_array(&mut invs);
 
-                CompressedRistretto(s.as_bytes())
-            })
-            .collect()
+        for ((state, inv), out) in states.iter().zip(invs.iter()).zip(out.iter_mut()) {
+            *out = state.compress(inv);
+        }
     }
 
     /// Return the coset self + E\[4\], for debugging.
@@ -654,41 +938,25 @@ impl RistrettoPoint {
     /// This method is not public because it's just used for hashing
     /// to a point -- proper elligator support is deferred for now.
     pub(crate) fn elligator_ristretto_flavor(r_0: &FieldElement) -> RistrettoPoint {
-        let i = &constants::SQRT_M1;
-        let d = &constants::EDWARDS_D;
-        let one_minus_d_sq = &constants::ONE_MINUS_EDWARDS_D_SQUARED;
-        let d_minus_one_sq = &constants::EDWARDS_D_MINUS_ONE_SQUARED;
-        let mut c = constants::MINUS_ONE;
-
-        let one = FieldElement::ONE;
-
-        let r = i * &r_0.square();
-        let N_s = &(&r + &one) * one_minus_d_sq;
-        let D = &(&c - &(d * &r)) * &(&r + d);
-
-        let (Ns_D_is_sq, mut s) = FieldElement::sqrt_ratio_i(&N_s, &D);
-        let mut s_prime = &s * r_0;
-        let s_prime_is_pos = !s_prime.is_negative();
-        s_prime.conditional_negate(s_prime_is_pos);
-
-        s.conditional_assign(&s_prime, !Ns_D_is_sq);
-        c.conditional_assign(&r, !Ns_D_is_sq);
-
-        let N_t = &(&(&c * &(&r - &one)) * d_minus_one_sq) - &D;
-        let s_sq = s.square();
+        let (r, N_s, D) = elligator::step_1(r_0);
+        let (Ns_D_is_sq, s) = FieldElement::sqrt_ratio_i(&N_s, &D);
+        elligator::step_2(r_0, &r, &D, Ns_D_is_sq, s)
+    }
 
-        use crate::backend::serial::curve_models::CompletedPoint;
+    /// Compute the Ristretto Elligator map of four field elements at once,
+    /// with the square roots computed by the selected backend.
+    #[cfg(feature = "alloc")]
+    #[allow(non_snake_case)]
+    pub(crate) fn elligator_ristretto_flavor_x4(r_0: &[FieldElement; 4]) -> [RistrettoPoint; 4] {
+        let steps = r_0.map(|r_0| elligator::step_1(&r_0));
+        let N_s = steps.map(|(_, N_s, _)| N_s);
+        let D = steps.map(|(_, _, D)| D);
+        let roots = crate::backend::sqrt_ratio_i_x4(&N_s, &D);
 
-        // The conversion from W_i is exactly the conversion from P1xP1.
-        RistrettoPoint(
-            CompletedPoint {
-                X: &(&s + &s) * &D,
-                Z: &N_t * &constants::SQRT_AD_MINUS_ONE,
-                Y: &FieldElement::ONE - &s_sq,
-                T: &FieldElement::ONE + &s_sq,
-            }
-            .as_extended(),
-        )
+        [0, 1, 2, 3].map(|i| {
+            let (Ns_D_is_sq, s) = roots[i];
+            elligator::step_2(&r_0[i], &steps[i].0, &D[i], Ns_D_is_sq, s)
+        })
     }
 
     #[cfg(any(test, feature = "rand_core"))]
@@ -716,6 +984,32 @@ impl RistrettoPoint {
         RistrettoPoint::from_uniform_bytes(&uniform_bytes)
     }
 
+    #[cfg(feature = "rand_core_0_9")]
+    /// Return a `RistrettoPoint` chosen uniformly at random using an RNG
+    /// implementing `rand_core` 0.9's `CryptoRng`.
+    ///
+    /// This is [`RistrettoPoint::random`] for the `rand_core` 0.9 traits,
+    /// and gives the same output for the same random bytes.
+    pub fn random_0_9<R: rand_core_0_9::CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        let mut uniform_bytes = [0u8; 64];
+        rng.fill_bytes(&mut uniform_bytes);
+
+        RistrettoPoint::from_uniform_bytes(&uniform_bytes)
+    }
+
+    #[cfg(feature = "rand_core_0_9")]
+    /// Return a `RistrettoPoint` chosen uniformly at random using a
+    /// fallible RNG implementing `rand_core` 0.9's `TryCryptoRng`, or the
+    /// RNG's error.
+    pub fn try_random_0_9<R: rand_core_0_9::TryCryptoRng + ?Sized>(
+        rng: &mut R,
+    ) -> Result<Self, R::Error> {
+        let mut uniform_bytes = [0u8; 64];
+        rng.try_fill_bytes(&mut uniform_bytes)?;
+
+        Ok(RistrettoPoint::from_uniform_bytes(&uniform_bytes))
+    }
+
     #[cfg(feature = "digest")]
     /// Hash a slice of bytes into a `RistrettoPoint`.
     ///
@@ -773,6 +1067,50 @@ impl RistrettoPoint {
         RistrettoPoint::from_uniform_bytes(&output_bytes)
     }
 
+    #[cfg(feature = "digest")]
+    /// Hash a message into a `RistrettoPoint`, under the domain separation
+    /// tag `dst`.
+    ///
+    /// This implements the `hash_to_ristretto255` function of [RFC 9380]
+    /// and [RFC 9496]: the message is expanded to 64 bytes with
+    /// `expand_message_xmd` using the hash function `D`, and the result is
+    /// mapped to the group with [`RistrettoPoint::from_uniform_bytes`].
+    /// With `D = Sha512`, this is the `ristretto255_XMD:SHA-512_R255MAP_RO_`
+    /// suite.
+    ///
+    /// Takes a type parameter `D`, which is any `Digest` producing 64
+    /// bytes of output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst` is empty, since domain separation tags are
+    /// required to be nonempty.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "digest", doc = "```")]
+    #[cfg_attr(not(feature = "digest"), doc = "```ignore")]
+    /// # use curve25519_dalek::ristretto::RistrettoPoint;
+    /// use sha2::Sha512;
+    ///
+    /// # fn main() {
+    /// let dst = b"MyProtocol-v1-ristretto255_XMD:SHA-512_R255MAP_RO_";
+    /// let P = RistrettoPoint::hash_with_dst::<Sha512>(dst, b"hello");
+    /// let Q = RistrettoPoint::hash_with_dst::<Sha512>(b"OtherProtocol-v1", b"hello");
+    ///
+    /// assert_ne!(P, Q);
+    /// # }
+    /// ```
+    ///
+    /// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+    /// [RFC 9496]: https://www.rfc-editor.org/rfc/rfc9496.html
+    pub fn hash_with_dst<D>(dst: &[u8], msg: &[u8]) -> RistrettoPoint
+    where
+        D: Digest<OutputSize = U64> + BlockSizeUser,
+    {
+        RistrettoPoint::from_uniform_bytes(&expand_message_xmd::<D>(dst, msg))
+    }
+
     /// Construct a `RistrettoPoint` from 64 bytes of data.
     ///
     /// If the input bytes are uniformly distributed, the resulting
@@ -801,6 +1139,225 @@ impl RistrettoPoint {
         // uniform distribution.
         R_1 + R_2
     }
+
+    /// Construct a `RistrettoPoint` from each 64-byte string in `inputs`.
+    ///
+    /// This returns the same results as calling
+    /// [`RistrettoPoint::from_uniform_bytes`] on each input, but applies
+    /// the Elligator map to four field elements at a time, which on CPUs
+    /// with AVX2 or AVX512 IFMA runs in parallel vector lanes.
+    #[cfg(feature = "alloc")]
+    pub fn from_uniform_bytes_batch(inputs: &[[u8; 64]]) -> Vec<RistrettoPoint> {
+        let halves = |bytes: &[u8; 64]| {
+            let mut r_1_bytes = [0u8; 32];
+            r_1_bytes.copy_from_slice(&bytes[0..32]);
+            let mut r_2_bytes = [0u8; 32];
+            r_2_bytes.copy_from_slice(&bytes[32..64]);
+            [
+                FieldElement::from_bytes(&r_1_bytes),
+                FieldElement::from_bytes(&r_2_bytes),
+            ]
+        };
+
+        let mut points = Vec::with_capacity(inputs.len());
+        let mut pairs = inputs.chunks_exact(2);
+        for pair in &mut pairs {
+            let [r_1, r_2] = halves(&pair[0]);
+            let [r_3, r_4] = halves(&pair[1]);
+            let R = RistrettoPoint::elligator_ristretto_flavor_x4(&[r_1, r_2, r_3, r_4]);
+            points.push(R[0] + R[1]);
+            points.push(R[2] + R[3]);
+        }
+        points.extend(
+            pairs
+                .remainder()
+                .iter()
+                .map(RistrettoPoint::from_uniform_bytes),
+        );
+        points
+    }
+
+    /// Decode a `RistrettoPoint` from a uniform representative produced by
+    /// [`RistrettoPoint::to_uniform_representative`].
+    ///
+    /// Every 32-byte string decodes to some point, so unlike
+    /// [`CompressedRistretto::decompress`] this function cannot fail.
+    ///
+    /// # Implementation
+    ///
+    /// This takes the low 255 bits of `bytes` mod p and applies the
+    /// Ristretto-flavored Elligator map to them once.
+    pub fn from_uniform_representative(bytes: &[u8; 32]) -> RistrettoPoint {
+        RistrettoPoint::elligator_ristretto_flavor(&FieldElement::from_bytes(bytes))
+    }
+
+    /// Attempt to encode this point as a string of 32 bytes which is
+    /// indistinguishable from uniformly random, by inverting the
+    /// Ristretto-flavored Elligator map.
+    ///
+    /// A point has a handful of preimages under the Elligator map, out of
+    /// 32 candidates.  The `hint` selects one candidate preimage (and the
+    /// value of the otherwise-ignored high bit of the output); the result
+    /// is `None` if that candidate is not a preimage of `self`.
+    ///
+    /// To obtain uniformly distributed representatives, the `hint` **must**
+    /// be chosen uniformly at random, and a point whose representative
+    /// computation fails must be discarded, rather than retried with
+    /// another `hint`.  In other words, this is meant to be used at key
+    /// generation time, generating new keypairs until one succeeds; see
+    /// [`RistrettoPoint::random_with_uniform_representative`].
+    ///
+    /// This function runs in constant time.
+    ///
+    /// # Return
+    ///
+    /// - `Some(bytes)` such that
+    ///   `RistrettoPoint::from_uniform_representative(&bytes) == *self`, if
+    ///   the candidate selected by `hint` is a preimage of `self`;
+    /// - `None` otherwise.
+    pub fn to_uniform_representative(&self, hint: u8) -> CtOption<[u8; 32]> {
+        let hint_bit = |i: u8| Choice::from((hint >> i) & 1);
+
+        // Bits 0 and 1 of the hint select a representative of the coset
+        // self + E[4].  Each member of the coset is the image of a pair of
+        // points (±s, ±t) on the Jacobi quartic, and s^2 = (1 - y)/(1 + y).
+        let coset = self.coset4();
+        let mut P = coset[0];
+        P.conditional_assign(&coset[1], hint_bit(0) & !hint_bit(1));
+        P.conditional_assign(&coset[2], !hint_bit(0) & hint_bit(1));
+        P.conditional_assign(&coset[3], hint_bit(0) & hint_bit(1));
+
+        let s2_num = &P.Z - &P.Y;
+        let s2_den = &P.Z + &P.Y;
+
+        // If the Elligator map sends r0 to (s, t), with r = i*r0^2, then
+        // either N_s/D was square and
+        //
+        //     s^2 d r^2 + (s^2 (1 + d^2) + 1 - d^2) r + (s^2 d + 1 - d^2) = 0,
+        //
+        // or it was nonsquare, s^2 = r N_s/D, and r satisfies the same
+        // equation with the coefficients reversed.  The equations below are
+        // scaled by the denominator of s^2.
+        //
+        // Only 2a and 2c are needed, and they are computed directly so that
+        // no field element accumulates more than two unreduced additions.
+        let d = &constants::EDWARDS_D;
+        let one_minus_d_sq = &constants::ONE_MINUS_EDWARDS_D_SQUARED;
+        let two_one_minus_d_sq = one_minus_d_sq + one_minus_d_sq;
+        let one_plus_d_sq = &FieldElement::ONE + &d.square();
+
+        let a = &s2_num * d;
+        let two_a = &a + &a;
+        let b = &(&s2_num * &one_plus_d_sq) + &(&s2_den * one_minus_d_sq);
+        let two_c = &two_a + &(&s2_den * &two_one_minus_d_sq);
+
+        let discriminant = &b.square() - &(&two_a * &two_c);
+        let (discriminant_is_square, mut sqrt_discriminant) =
+            FieldElement::sqrt_ratio_i(&discriminant, &FieldElement::ONE);
+
+        // Bit 2 selects the root of the quadratic, and bit 3 selects whether
+        // N_s/D was square.
+        sqrt_discriminant.conditional_negate(hint_bit(2));
+        let r_num = &sqrt_discriminant - &b;
+        let mut r_den = two_c;
+        r_den.conditional_assign(&two_a, !hint_bit(3));
+
+        // r0 = sqrt(r/i) = sqrt(-i r), with bit 4 selecting its sign.
+        let (r_over_i_is_square, mut r_0) =
+            FieldElement::sqrt_ratio_i(&-&(&r_num * &constants::SQRT_M1), &r_den);
+        r_0.conditional_negate(hint_bit(4));
+
+        // The algebra above only finds candidates; check that this one is in
+        // fact a preimage.
+        let is_preimage = RistrettoPoint::elligator_ristretto_flavor(&r_0).ct_eq(self);
+
+        // Bit 7 fills the high bit, which is ignored when decoding.
+        let mut bytes = r_0.as_bytes();
+        bytes[31] |= hint & 0x80;
+
+        CtOption::new(
+            bytes,
+            discriminant_is_square & r_over_i_is_square & is_preimage,
+        )
+    }
+
+    #[cfg(any(test, feature = "rand_core"))]
+    /// Generate a random secret scalar \\(x\\), together with the point
+    /// \\(P = xB\\) and a uniform representative of \\(P\\).
+    ///
+    /// Key pairs whose public key has no representative are discarded, and
+    /// generation is retried, so that the returned representative is
+    /// indistinguishable from a uniformly random string.  On average,
+    /// around four key pairs are generated per call.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(x, P, bytes)` with `P == RistrettoPoint::mul_base(&x)` and
+    /// `RistrettoPoint::from_uniform_representative(&bytes) == P`.
+    pub fn random_with_uniform_representative<R: CryptoRngCore + ?Sized>(
+        rng: &mut R,
+    ) -> (Scalar, RistrettoPoint, [u8; 32]) {
+        loop {
+            let x = Scalar::random(rng);
+            let P = RistrettoPoint::mul_base(&x);
+
+            let mut hint = [0u8; 1];
+            rng.fill_bytes(&mut hint);
+
+            if let Some(bytes) = P.to_uniform_representative(hint[0]).into() {
+                return (x, P, bytes);
+            }
+        }
+    }
+}
+
+/// The `expand_message_xmd` function of [RFC 9380 section 5.3.1], specialised
+/// to a 64-byte output and a hash function with a 64-byte digest.
+///
+/// [RFC 9380 section 5.3.1]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.1
+#[cfg(feature = "digest")]
+fn expand_message_xmd<D>(dst: &[u8], msg: &[u8]) -> [u8; 64]
+where
+    D: Digest<OutputSize = U64> + BlockSizeUser,
+{
+    assert!(!dst.is_empty(), "domain separation tag must be nonempty");
+
+    // Oversized tags are replaced by their hash (RFC 9380 section 5.3.3).
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = D::new()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(dst)
+            .finalize();
+        oversize_dst.as_slice()
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    // The output length is a single digest, so ell = 1 and only b_0 and
+    // b_1 are needed.
+    let mut hash = D::new();
+    for _ in 0..D::block_size() {
+        hash.update([0u8]);
+    }
+    let b_0 = hash
+        .chain_update(msg)
+        .chain_update(64u16.to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+    let b_1 = D::new()
+        .chain_update(b_0)
+        .chain_update([1u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    let mut output = [0u8; 64];
+    output.copy_from_slice(b_1.as_slice());
+    output
 }
 
 impl Identity for RistrettoPoint {
@@ -1006,6 +1563,36 @@ impl VartimeMultiscalarMul for RistrettoPoint {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl RistrettoPoint {
+    /// Compute \\(\sum\_i s\_i P\_i\\) in variable time with the given
+    /// multiscalar multiplication `algorithm`.
+    ///
+    /// See [`EdwardsPoint::vartime_multiscalar_mul_with_algorithm`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    pub fn vartime_multiscalar_mul_with_algorithm<I, J>(
+        algorithm: MultiscalarMulAlgorithm,
+        scalars: I,
+        points: J,
+    ) -> RistrettoPoint
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<RistrettoPoint>,
+    {
+        let extended_points = points.into_iter().map(|P| P.borrow().0);
+        RistrettoPoint(EdwardsPoint::vartime_multiscalar_mul_with_algorithm(
+            algorithm,
+            scalars,
+            extended_points,
+        ))
+    }
+}
+
 /// Precomputation for variable-time multiscalar multiplication with `RistrettoPoint`s.
 // This wraps the inner implementation in a facade type so that we can
 // decouple stability of the inner type from the stability of the
@@ -1013,54 +1600,265 @@ impl VartimeMultiscalarMul for RistrettoPoint {
 #[cfg(feature = "alloc")]
 pub struct VartimeRistrettoPrecomputation(crate::backend::VartimePrecomputedStraus);
 
-#[cfg(feature = "alloc")]
-impl VartimePrecomputedMultiscalarMul for VartimeRistrettoPrecomputation {
-    type Point = RistrettoPoint;
+#[cfg(feature = "alloc")]
+impl VartimePrecomputedMultiscalarMul for VartimeRistrettoPrecomputation {
+    type Point = RistrettoPoint;
+
+    fn new<I>(static_points: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Point>,
+    {
+        Self(crate::backend::VartimePrecomputedStraus::new(
+            static_points.into_iter().map(|P| P.borrow().0),
+        ))
+    }
+
+    fn optional_mixed_multiscalar_mul<I, J, K>(
+        &self,
+        static_scalars: I,
+        dynamic_scalars: J,
+        dynamic_points: K,
+    ) -> Option<Self::Point>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+        J: IntoIterator,
+        J::Item: Borrow<Scalar>,
+        K: IntoIterator<Item = Option<Self::Point>>,
+    {
+        self.0
+            .optional_mixed_multiscalar_mul(
+                static_scalars,
+                dynamic_scalars,
+                dynamic_points.into_iter().map(|P_opt| P_opt.map(|P| P.0)),
+            )
+            .map(RistrettoPoint)
+    }
+}
+
+impl RistrettoPoint {
+    /// Given an iterator of public scalars and a slice of compressed
+    /// points, compute either `Some(Q)`, where
+    /// $$
+    /// Q = c\_1 P\_1 + \cdots + c\_n P\_n,
+    /// $$
+    /// if every \\(P\_i\\) is the canonical encoding of a point, or else
+    /// return `None`.
+    ///
+    /// This is the common case when verifying proofs, which arrive with
+    /// their points in compressed form.  All points are decompressed with
+    /// [`CompressedRistretto::decompress_batch`], which computes the
+    /// inverse square roots four at a time, before the multiscalar
+    /// multiplication starts.
+    ///
+    /// It is an error to call this function with a number of scalars
+    /// different from the number of points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::constants;
+    /// use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
+    /// use curve25519_dalek::scalar::Scalar;
+    /// use curve25519_dalek::traits::VartimeMultiscalarMul;
+    ///
+    /// let abc = [Scalar::from(3u64), Scalar::from(5u64), Scalar::from(7u64)];
+    ///
+    /// let P = constants::RISTRETTO_BASEPOINT_POINT;
+    /// let PQR = [P, P + P, P + P + P];
+    /// let compressed: Vec<CompressedRistretto> = PQR.iter().map(|X| X.compress()).collect();
+    ///
+    /// let A = RistrettoPoint::vartime_multiscalar_mul_compressed(&abc, &compressed);
+    /// assert_eq!(A, Some(RistrettoPoint::vartime_multiscalar_mul(&abc, &PQR)));
+    ///
+    /// // A non-canonical encoding makes the whole computation fail.
+    /// let mut bad = compressed.clone();
+    /// bad[1] = CompressedRistretto([0xff; 32]);
+    /// assert!(RistrettoPoint::vartime_multiscalar_mul_compressed(&abc, &bad).is_none());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn vartime_multiscalar_mul_compressed<I>(
+        scalars: I,
+        points: &[CompressedRistretto],
+    ) -> Option<RistrettoPoint>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Scalar>,
+    {
+        let extended_points = CompressedRistretto::decompress_batch(points)
+            .into_iter()
+            .map(|P| P.map(|P| P.0));
+
+        EdwardsPoint::optional_multiscalar_mul(scalars, extended_points).map(RistrettoPoint)
+    }
+
+    /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the
+    /// Ristretto basepoint.
+    pub fn vartime_double_scalar_mul_basepoint(
+        a: &Scalar,
+        A: &RistrettoPoint,
+        b: &Scalar,
+    ) -> RistrettoPoint {
+        RistrettoPoint(EdwardsPoint::vartime_double_scalar_mul_basepoint(
+            a, &A.0, b,
+        ))
+    }
+}
+
+// ------------------------------------------------------------------------
+// Generator chains
+// ------------------------------------------------------------------------
+
+/// Domain separator prepended to every `GeneratorsChain` hash input.
+#[cfg(feature = "digest")]
+const GENERATORS_CHAIN_DOMAIN: &[u8] = b"curve25519-dalek GeneratorsChain";
+
+/// A sequence of independent Ristretto generators, deterministically derived
+/// from a label.
+///
+/// The \\(i\\)-th generator is the hash to the group of the label and the
+/// counter \\(i\\), so the discrete log of any generator with respect to any
+/// other point should be unknown.  This is the construction needed by
+/// Bulletproofs and other inner-product arguments, which require many
+/// generators with no known relations between them.  The counter is 64
+/// bits, so the chain ends after \\(2\\^{64}\\) generators.
+///
+/// Takes a type parameter `D`, which is any `Digest` producing 64 bytes of
+/// output.
+///
+/// # Example
+///
+#[cfg_attr(feature = "digest", doc = "```")]
+#[cfg_attr(not(feature = "digest"), doc = "```ignore")]
+/// # use curve25519_dalek::ristretto::{GeneratorsChain, RistrettoPoint};
+/// use sha2::Sha512;
+///
+/// # fn main() {
+/// let G: Vec<RistrettoPoint> = GeneratorsChain::<Sha512>::new(b"G").take(64).collect();
+/// let H: Vec<RistrettoPoint> = GeneratorsChain::<Sha512>::new(b"H").take(64).collect();
+///
+/// assert_ne!(G[0], H[0]);
+/// assert_eq!(GeneratorsChain::<Sha512>::new(b"G").nth(10), Some(G[10]));
+/// # }
+/// ```
+#[cfg(feature = "digest")]
+#[derive(Clone)]
+pub struct GeneratorsChain<D> {
+    /// The hash state after absorbing the domain separator and the label.
+    prefix: D,
+    /// The index of the next generator to be produced, or `None` once the
+    /// counter has been exhausted.
+    counter: Option<u64>,
+}
+
+#[cfg(feature = "digest")]
+impl<D> GeneratorsChain<D>
+where
+    D: Digest<OutputSize = U64> + Default + Clone,
+{
+    /// Create a new chain of generators determined by `label`.
+    pub fn new(label: &[u8]) -> Self {
+        let mut prefix = D::new();
+        prefix.update(GENERATORS_CHAIN_DOMAIN);
+        // Length-prefix the label so that the label and the counter cannot
+        // be confused with one another.
+        prefix.update((label.len() as u64).to_le_bytes());
+        prefix.update(label);
+
+        GeneratorsChain {
+            prefix,
+            counter: Some(0),
+        }
+    }
+
+    /// Return the `index`-th generator of this chain, without advancing it.
+    pub fn generator(&self, index: u64) -> RistrettoPoint {
+        let mut hash = self.prefix.clone();
+        hash.update(index.to_le_bytes());
+        RistrettoPoint::from_hash(hash)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<D> Iterator for GeneratorsChain<D>
+where
+    D: Digest<OutputSize = U64> + Default + Clone,
+{
+    type Item = RistrettoPoint;
+
+    fn next(&mut self) -> Option<RistrettoPoint> {
+        let index = self.counter?;
+        self.counter = index.checked_add(1);
+        Some(self.generator(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match self.counter {
+            Some(index) => u128::from(u64::MAX - index) + 1,
+            None => 0,
+        };
+        match usize::try_from(remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<RistrettoPoint> {
+        // Skipping ahead doesn't require hashing the skipped generators.
+        self.counter = self
+            .counter
+            .and_then(|index| index.checked_add(u64::try_from(n).ok()?));
+        self.next()
+    }
+}
+
+/// A lazily-extended cache of the generators of a [`GeneratorsChain`].
+///
+/// Proof systems typically need the same set of generators over and over,
+/// with the required number depending on the statement being proven.  This
+/// type derives generators on demand and keeps them around, so each
+/// generator is only hashed to the group once.
+#[cfg(all(feature = "digest", feature = "alloc"))]
+#[derive(Clone)]
+pub struct GeneratorsCache<D> {
+    chain: GeneratorsChain<D>,
+    generators: Vec<RistrettoPoint>,
+}
+
+#[cfg(all(feature = "digest", feature = "alloc"))]
+impl<D> GeneratorsCache<D>
+where
+    D: Digest<OutputSize = U64> + Default + Clone,
+{
+    /// Create a new, empty cache for the chain of generators determined by
+    /// `label`.
+    pub fn new(label: &[u8]) -> Self {
+        GeneratorsCache {
+            chain: GeneratorsChain::new(label),
+            generators: Vec::new(),
+        }
+    }
 
-    fn new<I>(static_points: I) -> Self
-    where
-        I: IntoIterator,
-        I::Item: Borrow<Self::Point>,
-    {
-        Self(crate::backend::VartimePrecomputedStraus::new(
-            static_points.into_iter().map(|P| P.borrow().0),
-        ))
+    /// Return the first `n` generators of the chain, deriving and caching
+    /// any which have not been computed yet.
+    pub fn generators(&mut self, n: usize) -> &[RistrettoPoint] {
+        if n > self.generators.len() {
+            let missing = n - self.generators.len();
+            self.generators.reserve(missing);
+            self.generators.extend((&mut self.chain).take(missing));
+        }
+        &self.generators[..n]
     }
 
-    fn optional_mixed_multiscalar_mul<I, J, K>(
-        &self,
-        static_scalars: I,
-        dynamic_scalars: J,
-        dynamic_points: K,
-    ) -> Option<Self::Point>
-    where
-        I: IntoIterator,
-        I::Item: Borrow<Scalar>,
-        J: IntoIterator,
-        J::Item: Borrow<Scalar>,
-        K: IntoIterator<Item = Option<Self::Point>>,
-    {
-        self.0
-            .optional_mixed_multiscalar_mul(
-                static_scalars,
-                dynamic_scalars,
-                dynamic_points.into_iter().map(|P_opt| P_opt.map(|P| P.0)),
-            )
-            .map(RistrettoPoint)
+    /// Return the number of generators currently held in the cache.
+    pub fn len(&self) -> usize {
+        self.generators.len()
     }
-}
 
-impl RistrettoPoint {
-    /// Compute \\(aA + bB\\) in variable time, where \\(B\\) is the
-    /// Ristretto basepoint.
-    pub fn vartime_double_scalar_mul_basepoint(
-        a: &Scalar,
-        A: &RistrettoPoint,
-        b: &Scalar,
-    ) -> RistrettoPoint {
-        RistrettoPoint(EdwardsPoint::vartime_double_scalar_mul_basepoint(
-            a, &A.0, b,
-        ))
+    /// Return `true` if no generators have been derived yet.
+    pub fn is_empty(&self) -> bool {
+        self.generators.is_empty()
     }
 }
 
@@ -1076,29 +1874,32 @@ impl RistrettoPoint {
 /// let a = Scalar::from(87329482u64);
 /// let P = &a * RISTRETTO_BASEPOINT_TABLE;
 /// ```
+///
+/// Tables can be built for any other generator, such as the blinding
+/// generator of a Pedersen commitment scheme, to get constant-time
+/// fixed-base multiplication by that generator as well:
+/// ```
+/// use curve25519_dalek::constants::{RISTRETTO_BASEPOINT_POINT, RISTRETTO_BASEPOINT_TABLE};
+/// use curve25519_dalek::ristretto::RistrettoBasepointTable;
+/// use curve25519_dalek::scalar::Scalar;
+///
+/// let H = RISTRETTO_BASEPOINT_POINT * Scalar::from(1234u64);
+/// let H_table = RistrettoBasepointTable::create(&H);
+///
+/// let (v, r) = (Scalar::from(5u64), Scalar::from(87329482u64));
+/// let commitment = &v * RISTRETTO_BASEPOINT_TABLE + &r * &H_table;
+///
+/// assert_eq!(H_table.basepoint(), H);
+/// assert_eq!(commitment, v * RISTRETTO_BASEPOINT_POINT + r * H);
+/// ```
+///
+/// With the `serde` feature, a table is serialized as the encoding of its
+/// basepoint, and is recomputed when it is deserialized.
 #[cfg(feature = "precomputed-tables")]
 #[derive(Clone)]
 #[repr(transparent)]
 pub struct RistrettoBasepointTable(pub(crate) EdwardsBasepointTable);
 
-#[cfg(feature = "precomputed-tables")]
-impl<'a, 'b> Mul<&'b Scalar> for &'a RistrettoBasepointTable {
-    type Output = RistrettoPoint;
-
-    fn mul(self, scalar: &'b Scalar) -> RistrettoPoint {
-        RistrettoPoint(&self.0 * scalar)
-    }
-}
-
-#[cfg(feature = "precomputed-tables")]
-impl<'a, 'b> Mul<&'a RistrettoBasepointTable> for &'b Scalar {
-    type Output = RistrettoPoint;
-
-    fn mul(self, basepoint_table: &'a RistrettoBasepointTable) -> RistrettoPoint {
-        RistrettoPoint(self * &basepoint_table.0)
-    }
-}
-
 #[cfg(feature = "precomputed-tables")]
 impl RistrettoBasepointTable {
     /// Create a precomputed table of multiples of the given `basepoint`.
@@ -1112,6 +1913,105 @@ impl RistrettoBasepointTable {
     }
 }
 
+/// A precomputed radix-32 table of multiples of a basepoint.  See
+/// [`RistrettoBasepointTable`] for the radix-16 table and
+/// [`EdwardsBasepointTable`] for the size trade-offs.
+#[cfg(feature = "precomputed-tables")]
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct RistrettoBasepointTableRadix32(pub(crate) EdwardsBasepointTableRadix32);
+
+/// A precomputed radix-64 table of multiples of a basepoint.  See
+/// [`RistrettoBasepointTable`] for the radix-16 table and
+/// [`EdwardsBasepointTable`] for the size trade-offs.
+#[cfg(feature = "precomputed-tables")]
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct RistrettoBasepointTableRadix64(pub(crate) EdwardsBasepointTableRadix64);
+
+/// A precomputed radix-128 table of multiples of a basepoint.  See
+/// [`RistrettoBasepointTable`] for the radix-16 table and
+/// [`EdwardsBasepointTable`] for the size trade-offs.
+#[cfg(feature = "precomputed-tables")]
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct RistrettoBasepointTableRadix128(pub(crate) EdwardsBasepointTableRadix128);
+
+/// A precomputed radix-256 table of multiples of a basepoint.  See
+/// [`RistrettoBasepointTable`] for the radix-16 table and
+/// [`EdwardsBasepointTable`] for the size trade-offs.
+#[cfg(feature = "precomputed-tables")]
+#[derive(Clone)]
+#[repr(transparent)]
+pub struct RistrettoBasepointTableRadix256(pub(crate) EdwardsBasepointTableRadix256);
+
+/// A type-alias for [`RistrettoBasepointTable`], for symmetry with
+/// [`EdwardsBasepointTableRadix16`](crate::edwards::EdwardsBasepointTableRadix16).
+#[cfg(feature = "precomputed-tables")]
+pub type RistrettoBasepointTableRadix16 = RistrettoBasepointTable;
+
+#[cfg(feature = "precomputed-tables")]
+macro_rules! impl_ristretto_basepoint_table {
+    (Name = $name:ident, Edwards = $edwards:ident) => {
+        impl BasepointTable for $name {
+            type Point = RistrettoPoint;
+
+            fn create(basepoint: &RistrettoPoint) -> $name {
+                $name($edwards::create(&basepoint.0))
+            }
+
+            fn basepoint(&self) -> RistrettoPoint {
+                RistrettoPoint(self.0.basepoint())
+            }
+
+            fn mul_base(&self, scalar: &Scalar) -> RistrettoPoint {
+                RistrettoPoint(self.0.mul_base(scalar))
+            }
+        }
+
+        impl<'a, 'b> Mul<&'b Scalar> for &'a $name {
+            type Output = RistrettoPoint;
+
+            fn mul(self, scalar: &'b Scalar) -> RistrettoPoint {
+                RistrettoPoint(&self.0 * scalar)
+            }
+        }
+
+        impl<'a, 'b> Mul<&'a $name> for &'b Scalar {
+            type Output = RistrettoPoint;
+
+            fn mul(self, basepoint_table: &'a $name) -> RistrettoPoint {
+                RistrettoPoint(self * &basepoint_table.0)
+            }
+        }
+    };
+}
+
+cfg_if! {
+    if #[cfg(feature = "precomputed-tables")] {
+        impl_ristretto_basepoint_table! {
+            Name = RistrettoBasepointTable,
+            Edwards = EdwardsBasepointTable
+        }
+        impl_ristretto_basepoint_table! {
+            Name = RistrettoBasepointTableRadix32,
+            Edwards = EdwardsBasepointTableRadix32
+        }
+        impl_ristretto_basepoint_table! {
+            Name = RistrettoBasepointTableRadix64,
+            Edwards = EdwardsBasepointTableRadix64
+        }
+        impl_ristretto_basepoint_table! {
+            Name = RistrettoBasepointTableRadix128,
+            Edwards = EdwardsBasepointTableRadix128
+        }
+        impl_ristretto_basepoint_table! {
+            Name = RistrettoBasepointTableRadix256,
+            Edwards = EdwardsBasepointTableRadix256
+        }
+    }
+}
+
 // ------------------------------------------------------------------------
 // Constant-time conditional selection
 // ------------------------------------------------------------------------
@@ -1160,6 +2060,53 @@ impl Debug for CompressedRistretto {
     }
 }
 
+impl core::fmt::Display for CompressedRistretto {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::encoding::fmt_hex(self.as_bytes(), f)
+    }
+}
+
+impl core::fmt::LowerHex for CompressedRistretto {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::encoding::fmt_hex(self.as_bytes(), f)
+    }
+}
+
+impl core::str::FromStr for CompressedRistretto {
+    type Err = ParseHexError;
+
+    /// Parse 64 hex digits, accepting only a valid Ristretto encoding.
+    fn from_str(s: &str) -> Result<CompressedRistretto, ParseHexError> {
+        let compressed = CompressedRistretto(crate::encoding::decode_hex(s)?);
+        match compressed.decompress() {
+            Some(_) => Ok(compressed),
+            None => Err(ParseHexError::InvalidEncoding),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CompressedRistretto {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "CompressedRistretto({=u64:016x}...)",
+            crate::encoding::short_hex(self.as_bytes())
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for RistrettoPoint {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "RistrettoPoint({=u64:016x}...)",
+            crate::encoding::short_hex(self.compress().as_bytes())
+        )
+    }
+}
+
 impl Debug for RistrettoPoint {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let coset = self.coset4();
@@ -1305,6 +2252,85 @@ mod test {
         assert_eq!(bp, constants::RISTRETTO_BASEPOINT_POINT);
     }
 
+    #[test]
+    #[cfg(all(feature = "serde", feature = "precomputed-tables"))]
+    fn serde_bincode_custom_basepoint_table_roundtrip() {
+        use bincode;
+
+        let H = constants::RISTRETTO_BASEPOINT_POINT * Scalar::from(1234u64);
+        let table = RistrettoBasepointTable::create(&H);
+
+        let encoded = bincode::serialize(&table).unwrap();
+        assert_eq!(encoded, bincode::serialize(&H.compress()).unwrap());
+
+        let decoded: RistrettoBasepointTable = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded.basepoint(), H);
+
+        let s = Scalar::from(999u64);
+        assert_eq!(&s * &decoded, H * s);
+    }
+
+    #[test]
+    #[cfg(feature = "precomputed-tables")]
+    fn basepoint_table_radices_agree() {
+        fn check<T: BasepointTable<Point = RistrettoPoint>>(P: &RistrettoPoint, s: &Scalar) {
+            let table = T::create(P);
+            assert_eq!(table.basepoint(), *P);
+            assert_eq!(table.mul_base(s), P * s);
+        }
+
+        let mut rng = OsRng;
+        let P = RistrettoPoint::random(&mut rng);
+        let s = Scalar::random(&mut rng);
+        check::<RistrettoBasepointTableRadix16>(&P, &s);
+        check::<RistrettoBasepointTableRadix32>(&P, &s);
+        check::<RistrettoBasepointTableRadix64>(&P, &s);
+        check::<RistrettoBasepointTableRadix128>(&P, &s);
+        check::<RistrettoBasepointTableRadix256>(&P, &s);
+
+        let table = RistrettoBasepointTableRadix64::create(&P);
+        assert_eq!(&s * &table, &table * &s);
+        assert_eq!(&s * &table, P * s);
+    }
+
+    #[test]
+    #[cfg(feature = "bytemuck")]
+    fn compressed_ristretto_bytemuck() {
+        let points = [
+            constants::RISTRETTO_BASEPOINT_COMPRESSED,
+            CompressedRistretto::identity(),
+        ];
+        let bytes: &[u8] = bytemuck::cast_slice(&points);
+        assert_eq!(
+            &bytes[..32],
+            constants::RISTRETTO_BASEPOINT_COMPRESSED.as_bytes()
+        );
+        assert_eq!(&bytes[32..], &[0u8; 32]);
+
+        let back: &[CompressedRistretto] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, &points);
+        assert_eq!(
+            bytemuck::cast::<_, CompressedEdwardsY>(constants::ED25519_BASEPOINT_COMPRESSED.0),
+            constants::ED25519_BASEPOINT_COMPRESSED
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn compressed_ristretto_ord() {
+        use alloc::collections::BTreeSet;
+
+        let mut rng = OsRng;
+        let points: Vec<CompressedRistretto> = (0..16)
+            .map(|_| RistrettoPoint::random(&mut rng).compress())
+            .collect();
+        let set: BTreeSet<CompressedRistretto> = points.iter().chain(&points).copied().collect();
+        assert_eq!(set.len(), points.len());
+
+        let sorted: Vec<CompressedRistretto> = set.into_iter().collect();
+        assert!(sorted.windows(2).all(|w| w[0].as_bytes() < w[1].as_bytes()));
+    }
+
     #[test]
     fn scalarmult_ristrettopoint_works_both_ways() {
         let P = constants::RISTRETTO_BASEPOINT_POINT;
@@ -1794,6 +2820,34 @@ mod test {
         }
     }
 
+    #[test]
+    fn uniform_representative_roundtrip() {
+        use rand_core::RngCore;
+
+        let mut rng = OsRng;
+        for _ in 0..32 {
+            // Every image of the Elligator map must be invertible with some hint.
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes);
+            let P = RistrettoPoint::from_uniform_representative(&bytes);
+
+            let mut found = false;
+            for hint in 0..=255u8 {
+                let repr: Option<[u8; 32]> = P.to_uniform_representative(hint).into();
+                if let Some(repr) = repr {
+                    assert_eq!(RistrettoPoint::from_uniform_representative(&repr), P);
+                    assert_eq!(repr[31] & 0x80, hint & 0x80);
+                    found = true;
+                }
+            }
+            assert!(found);
+        }
+
+        let (x, P, repr) = RistrettoPoint::random_with_uniform_representative(&mut rng);
+        assert_eq!(P, RistrettoPoint::mul_base(&x));
+        assert_eq!(RistrettoPoint::from_uniform_representative(&repr), P);
+    }
+
     #[test]
     fn random_roundtrip() {
         let mut rng = OsRng;
@@ -1822,6 +2876,185 @@ mod test {
         }
     }
 
+    #[test]
+    #[cfg(feature = "rand_core")]
+    fn double_and_compress_into_random_points() {
+        let mut rng = OsRng;
+
+        let mut points = [RistrettoPoint::identity(); 64];
+        for P in points.iter_mut().skip(1) {
+            *P = RistrettoPoint::random(&mut rng);
+        }
+        let mut compressed = [CompressedRistretto::default(); 64];
+
+        RistrettoPoint::double_and_compress_into(&points, &mut compressed);
+
+        for (P, P2_compressed) in points.iter().zip(compressed.iter()) {
+            assert_eq!(*P2_compressed, (P + P).compress());
+        }
+
+        // An empty batch is a no-op.
+        RistrettoPoint::double_and_compress_into(&[], &mut []);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "digest"))]
+    fn generators_chain_and_cache() {
+        use sha2::Sha512;
+
+        let chain: Vec<RistrettoPoint> = GeneratorsChain::<Sha512>::new(b"G").take(16).collect();
+
+        // Generators are distinct, and depend on the label.
+        for (i, P) in chain.iter().enumerate() {
+            assert_ne!(*P, RistrettoPoint::identity());
+            for Q in &chain[i + 1..] {
+                assert_ne!(P, Q);
+            }
+        }
+        let other = GeneratorsChain::<Sha512>::new(b"H").next();
+        assert_ne!(Some(chain[0]), other);
+
+        // Skipping ahead agrees with iterating.
+        let mut skipped = GeneratorsChain::<Sha512>::new(b"G");
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(skipped.size_hint(), (usize::MAX, None));
+        assert_eq!(skipped.nth(5), Some(chain[5]));
+        assert_eq!(skipped.next(), Some(chain[6]));
+
+        // The chain ends when the counter is exhausted, instead of wrapping.
+        let mut last = GeneratorsChain::<Sha512>::new(b"G");
+        last.counter = Some(u64::MAX - 1);
+        assert_eq!(last.size_hint(), (2, Some(2)));
+        assert_eq!(last.clone().nth(1), Some(last.generator(u64::MAX)));
+        assert_eq!(last.clone().nth(2), None);
+        assert!(last.next().is_some());
+        assert!(last.next().is_some());
+        assert_eq!(last.size_hint(), (0, Some(0)));
+        assert_eq!(last.next(), None);
+        assert_eq!(last.nth(1), None);
+
+        // The cache agrees with the chain as it grows.
+        let mut cache = GeneratorsCache::<Sha512>::new(b"G");
+        assert!(cache.is_empty());
+        assert_eq!(cache.generators(4), &chain[..4]);
+        assert_eq!(cache.generators(16), &chain[..]);
+        assert_eq!(cache.generators(8), &chain[..8]);
+        assert_eq!(cache.len(), 16);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn from_uniform_bytes_batch_matches_from_uniform_bytes() {
+        use rand_core::RngCore;
+
+        let mut rng = OsRng;
+        for n in 0..6 {
+            let mut inputs = vec![[0u8; 64]; n];
+            for input in inputs.iter_mut() {
+                rng.fill_bytes(input);
+            }
+            let expected: Vec<RistrettoPoint> = inputs
+                .iter()
+                .map(RistrettoPoint::from_uniform_bytes)
+                .collect();
+            assert_eq!(RistrettoPoint::from_uniform_bytes_batch(&inputs), expected);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    fn compress_batch_matches_compress() {
+        let mut rng = OsRng;
+
+        // 66 points, so that the last two take the serial path.
+        let mut points: Vec<RistrettoPoint> =
+            (0..66).map(|_| RistrettoPoint::random(&mut rng)).collect();
+        points[10] = RistrettoPoint::identity();
+        points[65] = RistrettoPoint::identity();
+
+        let compressed = RistrettoPoint::compress_batch(&points);
+
+        assert_eq!(compressed.len(), points.len());
+        for (P, P_compressed) in points.iter().zip(compressed.iter()) {
+            assert_eq!(*P_compressed, P.compress());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vartime_multiscalar_mul_compressed_vs_decompressed() {
+        let mut rng = rand::thread_rng();
+
+        let scalars = (0..64)
+            .map(|_| Scalar::random(&mut rng))
+            .collect::<Vec<_>>();
+        let points = scalars
+            .iter()
+            .map(|s| RistrettoPoint::mul_base(&s.invert()))
+            .collect::<Vec<_>>();
+        let compressed = points.iter().map(|P| P.compress()).collect::<Vec<_>>();
+
+        let Q = RistrettoPoint::vartime_multiscalar_mul_compressed(&scalars, &compressed);
+        assert_eq!(Q, Some(RistrettoPoint::mul_base(&Scalar::from(64u64))));
+
+        let mut bad = compressed.clone();
+        bad[63] = CompressedRistretto(constants::EDWARDS_D.as_bytes());
+        assert!(RistrettoPoint::vartime_multiscalar_mul_compressed(&scalars, &bad).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decompress_batch_rejects_invalid_in_every_position() {
+        let mut rng = rand::thread_rng();
+
+        // Six points: one chunk of four, and a remainder of two.
+        let scalars = (0..6).map(|_| Scalar::random(&mut rng)).collect::<Vec<_>>();
+        let points = scalars
+            .iter()
+            .map(RistrettoPoint::mul_base)
+            .collect::<Vec<_>>();
+        let compressed = points.iter().map(|P| P.compress()).collect::<Vec<_>>();
+
+        let decompressed = CompressedRistretto::decompress_batch(&compressed);
+        assert_eq!(
+            decompressed,
+            points.iter().map(|P| Some(*P)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            RistrettoPoint::vartime_multiscalar_mul_compressed(&scalars, &compressed),
+            Some(RistrettoPoint::vartime_multiscalar_mul(&scalars, &points))
+        );
+
+        // A negative s, a non-canonical s, and a canonical s which is not
+        // the encoding of a point.
+        let mut negative = [0u8; 32];
+        negative[0] = 1;
+        let mut non_canonical = [0xff; 32];
+        non_canonical[0] = 0xed;
+        non_canonical[31] = 0x7f;
+        let invalid = [
+            CompressedRistretto(negative),
+            CompressedRistretto(non_canonical),
+            CompressedRistretto(constants::EDWARDS_D.as_bytes()),
+        ];
+
+        for i in 0..compressed.len() {
+            for bad_encoding in invalid.iter() {
+                assert!(bad_encoding.decompress().is_none());
+
+                let mut bad = compressed.clone();
+                bad[i] = *bad_encoding;
+
+                let mut expected = decompressed.clone();
+                expected[i] = None;
+                assert_eq!(CompressedRistretto::decompress_batch(&bad), expected);
+                assert!(
+                    RistrettoPoint::vartime_multiscalar_mul_compressed(&scalars, &bad).is_none()
+                );
+            }
+        }
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn vartime_precomputed_vs_nonprecomputed_multiscalar() {
@@ -1869,4 +3102,44 @@ mod test {
         assert_eq!(P.compress(), R.compress());
         assert_eq!(Q.compress(), R.compress());
     }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn hash_with_dst_expand_message_xmd() {
+        use sha2::Sha512;
+
+        // Expected outputs computed independently with Python's hashlib,
+        // following RFC 9380 section 5.3.1.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        let expected = hex::decode(
+            "4a05d1b49d7153fb512df83b8564fe1754c607e2fbbc3d97c591fa175b6fca1e\
+             fb300462d96ed613f1534ecb260671eb8469a20071049dc8021b986828540592",
+        )
+        .unwrap();
+        assert_eq!(
+            &expand_message_xmd::<Sha512>(dst, b"abc")[..],
+            &expected[..]
+        );
+        assert_eq!(
+            RistrettoPoint::hash_with_dst::<Sha512>(dst, b"abc"),
+            RistrettoPoint::from_uniform_bytes(&expected.try_into().unwrap()),
+        );
+
+        // Oversized tags are hashed down first.
+        let long_dst = [b'x'; 300];
+        let expected = hex::decode(
+            "04529d4e578bd6324484cbc300ad41bc5b03e5fa1a19ceb20a110f3a9e03f276\
+             fb6b6d7a196edd386f8b41c91a5f287de61954a9209d0c5899c738e02fc9a1bc",
+        )
+        .unwrap();
+        assert_eq!(
+            &expand_message_xmd::<Sha512>(&long_dst, b"")[..],
+            &expected[..]
+        );
+
+        assert_ne!(
+            RistrettoPoint::hash_with_dst::<Sha512>(b"dst-a", b"msg"),
+            RistrettoPoint::hash_with_dst::<Sha512>(b"dst-b", b"msg"),
+        );
+    }
 }
@@ -137,11 +137,15 @@ use rand_core::CryptoRngCore;
 use digest::generic_array::typenum::U64;
 #[cfg(feature = "digest")]
 use digest::Digest;
+#[cfg(feature = "digest")]
+use digest::{ExtendableOutput, Update, XofReader};
 
 use subtle::Choice;
+use subtle::ConditionallyNegatable;
 use subtle::ConditionallySelectable;
 use subtle::ConstantTimeEq;
 use subtle::CtOption;
+use subtle::{ConstantTimeGreater, ConstantTimeLess};
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
@@ -149,6 +153,20 @@ use zeroize::Zeroize;
 use crate::backend;
 use crate::constants;
 
+// Lagrange interpolation coefficients, for threshold schemes
+#[cfg(feature = "alloc")]
+pub mod lagrange;
+
+// Unreduced products, for deferring reductions
+mod wide;
+pub use self::wide::WideScalar;
+
+// A zeroize-on-drop handle for secret scalars
+#[cfg(feature = "zeroize")]
+mod secret;
+#[cfg(feature = "zeroize")]
+pub use self::secret::SecretScalar;
+
 cfg_if! {
     if #[cfg(curve25519_dalek_backend = "fiat")] {
         /// An `UnpackedScalar` represents an element of the field GF(l), optimized for speed.
@@ -231,6 +249,24 @@ pub struct Scalar {
     pub(crate) bytes: [u8; 32],
 }
 
+/// \\( 2\^{256} \bmod \ell \\), used to reduce inputs longer than 32 bytes.
+const TWO_POW_256: Scalar = Scalar {
+    bytes: [
+        0x1d, 0x95, 0x98, 0x8d, 0x74, 0x31, 0xec, 0xd6, 0x70, 0xcf, 0x7d, 0x73, 0xf4, 0x5b, 0xef,
+        0xc6, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x0f,
+    ],
+};
+
+/// \\( (\ell + 1) / 2 \\), the inverse of 2 modulo \\( \ell \\).
+const INV_TWO: Scalar = Scalar {
+    bytes: [
+        0xf7, 0xe9, 0x7a, 0x2e, 0x8d, 0x31, 0x09, 0x2c, 0x6b, 0xce, 0x7b, 0x51, 0xef, 0x7c, 0x6f,
+        0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x08,
+    ],
+};
+
 impl Scalar {
     /// Construct a `Scalar` by reducing a 256-bit little-endian integer
     /// modulo the group order \\( \ell \\).
@@ -251,6 +287,90 @@ impl Scalar {
         UnpackedScalar::from_bytes_wide(input).pack()
     }
 
+    /// Construct a `Scalar` by reducing a 512-bit integer, given as eight
+    /// little-endian 64-bit limbs, modulo the group order \\( \ell \\).
+    ///
+    /// This is [`Scalar::from_bytes_mod_order_wide`] for values that are
+    /// already held as machine words, such as products computed by a
+    /// hardware multiplier, and skips the round trip through bytes.  It
+    /// runs in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// // 2^64 + 5
+    /// let limbs = [5, 1, 0, 0, 0, 0, 0, 0];
+    /// let expected = Scalar::from(5u64) + Scalar::from(u64::MAX) + Scalar::ONE;
+    /// assert_eq!(Scalar::from_u64_limbs_mod_order_wide(&limbs), expected);
+    /// ```
+    pub fn from_u64_limbs_mod_order_wide(limbs: &[u64; 8]) -> Scalar {
+        UnpackedScalar::from_words_wide(limbs).pack()
+    }
+
+    /// Construct a `Scalar` by reducing a 256-bit big-endian integer
+    /// modulo the group order \\( \ell \\).
+    pub fn from_bytes_mod_order_be(bytes: [u8; 32]) -> Scalar {
+        let mut le_bytes = bytes;
+        le_bytes.reverse();
+        Scalar::from_bytes_mod_order(le_bytes)
+    }
+
+    /// Construct a `Scalar` by reducing a little-endian integer of any
+    /// length modulo the group order \\( \ell \\).
+    ///
+    /// This runs in constant time with respect to the contents of `bytes`,
+    /// but not its length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let mut bytes = [0u8; 100];
+    /// bytes[0] = 7;
+    /// assert_eq!(Scalar::from_bytes_mod_order_var_len(&bytes), Scalar::from(7u64));
+    /// ```
+    pub fn from_bytes_mod_order_var_len(bytes: &[u8]) -> Scalar {
+        // Horner's rule in base 2^256, starting from the most significant
+        // chunk, which is the last (and possibly short) one.
+        let mut acc = Scalar::ZERO;
+        for chunk in bytes.chunks(32).rev() {
+            let mut limb = [0u8; 32];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            acc = acc * TWO_POW_256 + Scalar::from_bytes_mod_order(limb);
+        }
+        acc
+    }
+
+    /// Construct a `Scalar` by reducing a big-endian integer of any length
+    /// modulo the group order \\( \ell \\), such as a hash output or the
+    /// contents of a DER `INTEGER`.
+    ///
+    /// This runs in constant time with respect to the contents of `bytes`,
+    /// but not its length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let mut bytes = [0u8; 100];
+    /// bytes[99] = 7;
+    /// assert_eq!(Scalar::from_bytes_mod_order_var_len_be(&bytes), Scalar::from(7u64));
+    /// ```
+    pub fn from_bytes_mod_order_var_len_be(bytes: &[u8]) -> Scalar {
+        // Horner's rule in base 2^256, starting from the most significant
+        // chunk, which is the first (and possibly short) one.
+        let mut acc = Scalar::ZERO;
+        for chunk in bytes.rchunks(32).rev() {
+            let mut limb = [0u8; 32];
+            for (dst, src) in limb.iter_mut().zip(chunk.iter().rev()) {
+                *dst = *src;
+            }
+            acc = acc * TWO_POW_256 + Scalar::from_bytes_mod_order(limb);
+        }
+        acc
+    }
+
     /// Attempt to construct a `Scalar` from a canonical byte representation.
     ///
     /// # Return
@@ -259,9 +379,111 @@ impl Scalar {
     ///   if `bytes` is a canonical byte representation modulo the group order \\( \ell \\);
     /// - `None` if `bytes` is not a canonical byte representation.
     pub fn from_canonical_bytes(bytes: [u8; 32]) -> CtOption<Scalar> {
+        CtOption::new(Scalar { bytes }, Scalar::is_canonical_bytes(&bytes))
+    }
+
+    /// Attempt to construct a `Scalar` from a canonical big-endian byte
+    /// representation.
+    ///
+    /// This is the inverse of [`Scalar::to_bytes_be`].  Use
+    /// [`Scalar::from_bytes_mod_order_be`] instead to reduce arbitrary
+    /// integers.
+    ///
+    /// # Return
+    ///
+    /// - `Some(s)`, where `s` is the `Scalar` corresponding to `bytes`,
+    ///   if `bytes` is a canonical big-endian representation modulo the group order \\( \ell \\);
+    /// - `None` if `bytes` is not a canonical byte representation.
+    pub fn from_canonical_bytes_be(mut bytes: [u8; 32]) -> CtOption<Scalar> {
+        bytes.reverse();
+        Scalar::from_canonical_bytes(bytes)
+    }
+
+    #[cfg(feature = "crypto-bigint")]
+    /// Construct a `Scalar` by reducing a 256-bit integer modulo the group
+    /// order \\( \ell \\).
+    pub fn from_u256_mod_order(x: &crypto_bigint::U256) -> Scalar {
+        use crypto_bigint::Encoding;
+        Scalar::from_bytes_mod_order(x.to_le_bytes())
+    }
+
+    #[cfg(feature = "crypto-bigint")]
+    /// Construct a `Scalar` by reducing a 512-bit integer modulo the group
+    /// order \\( \ell \\).  The reduction is done once, as by
+    /// [`Scalar::from_bytes_mod_order_wide`].
+    pub fn from_u512_mod_order(x: &crypto_bigint::U512) -> Scalar {
+        use crypto_bigint::Encoding;
+        Scalar::from_bytes_mod_order_wide(&x.to_le_bytes())
+    }
+
+    #[cfg(feature = "crypto-bigint")]
+    /// Attempt to construct a `Scalar` from a 256-bit integer, which must be
+    /// less than the group order \\( \ell \\).
+    ///
+    /// This is the inverse of the `From<Scalar>` conversion to `U256`.
+    pub fn from_canonical_u256(x: &crypto_bigint::U256) -> CtOption<Scalar> {
+        use crypto_bigint::Encoding;
+        Scalar::from_canonical_bytes(x.to_le_bytes())
+    }
+
+    /// Check whether `bytes` is the canonical encoding of a `Scalar`, i.e.
+    /// a little-endian integer less than the group order \\( \ell \\).
+    ///
+    /// This is the check performed by [`Scalar::from_canonical_bytes`].
+    /// Protocols that must reject malleable encodings, such as the `s`
+    /// component of a signature, can use it to decide validity before
+    /// decoding.  It runs in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// assert!(bool::from(Scalar::is_canonical_bytes(&[0u8; 32])));
+    /// assert!(!bool::from(Scalar::is_canonical_bytes(&[0xff; 32])));
+    /// ```
+    pub fn is_canonical_bytes(bytes: &[u8; 32]) -> Choice {
         let high_bit_unset = (bytes[31] >> 7).ct_eq(&0);
-        let candidate = Scalar { bytes };
-        CtOption::new(candidate, high_bit_unset & candidate.is_canonical())
+        high_bit_unset & Scalar { bytes: *bytes }.is_canonical()
+    }
+
+    /// Construct a `Scalar` from a canonical byte representation in a
+    /// `const` context.
+    ///
+    /// This is intended for defining constants; use
+    /// [`Scalar::from_canonical_bytes`] for runtime inputs, since this
+    /// function runs in variable time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is not a canonical byte representation modulo the
+    /// group order \\( \ell \\).  In a `const` item this is a compile-time
+    /// error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// const EIGHT: Scalar = Scalar::from_canonical_bytes_const([
+    ///     8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ///     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    /// ]);
+    /// assert_eq!(EIGHT, Scalar::from(8u64));
+    /// ```
+    pub const fn from_canonical_bytes_const(bytes: [u8; 32]) -> Scalar {
+        let l = constants::BASEPOINT_ORDER_PRIVATE.bytes;
+
+        // Compare with l, starting from the most significant byte.
+        let mut i = 32;
+        while i > 0 {
+            i -= 1;
+            if bytes[i] < l[i] {
+                return Scalar { bytes };
+            }
+            if bytes[i] > l[i] {
+                break;
+            }
+        }
+        panic!("scalar bytes are not canonical");
     }
 
     /// Construct a `Scalar` from the low 255 bits of a 256-bit integer. This breaks the invariant
@@ -303,6 +525,26 @@ impl ConstantTimeEq for Scalar {
     }
 }
 
+/// Compare `Scalar`s as the integers in \\([0, \ell)\\) given by their
+/// canonical encodings.
+impl ConstantTimeGreater for Scalar {
+    fn ct_gt(&self, other: &Self) -> Choice {
+        // Scan from the most significant byte down: self > other iff at the
+        // first differing byte, self's byte is greater.
+        let mut gt = Choice::from(0);
+        let mut eq_so_far = Choice::from(1);
+        for (a, b) in self.bytes.iter().zip(other.bytes.iter()).rev() {
+            gt |= eq_so_far & a.ct_gt(b);
+            eq_so_far &= a.ct_eq(b);
+        }
+        gt
+    }
+}
+
+/// Compare `Scalar`s as the integers in \\([0, \ell)\\) given by their
+/// canonical encodings.
+impl ConstantTimeLess for Scalar {}
+
 impl Index<usize> for Scalar {
     type Output = u8;
 
@@ -397,8 +639,6 @@ impl ConditionallySelectable for Scalar {
     }
 }
 
-#[cfg(feature = "serde")]
-use serde::de::Visitor;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -409,12 +649,7 @@ impl Serialize for Scalar {
     where
         S: Serializer,
     {
-        use serde::ser::SerializeTuple;
-        let mut tup = serializer.serialize_tuple(32)?;
-        for byte in self.as_bytes().iter() {
-            tup.serialize_element(byte)?;
-        }
-        tup.end()
+        crate::encoding::serialize_bytes(self.as_bytes(), serializer)
     }
 }
 
@@ -425,35 +660,9 @@ impl<'de> Deserialize<'de> for Scalar {
     where
         D: Deserializer<'de>,
     {
-        struct ScalarVisitor;
-
-        impl<'de> Visitor<'de> for ScalarVisitor {
-            type Value = Scalar;
-
-            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                formatter.write_str(
-                    "a sequence of 32 bytes whose little-endian interpretation is less than the \
-                    basepoint order ℓ",
-                )
-            }
-
-            fn visit_seq<A>(self, mut seq: A) -> Result<Scalar, A::Error>
-            where
-                A: serde::de::SeqAccess<'de>,
-            {
-                let mut bytes = [0u8; 32];
-                #[allow(clippy::needless_range_loop)]
-                for i in 0..32 {
-                    bytes[i] = seq
-                        .next_element()?
-                        .ok_or_else(|| serde::de::Error::invalid_length(i, &"expected 32 bytes"))?;
-                }
-                Option::from(Scalar::from_canonical_bytes(bytes))
-                    .ok_or_else(|| serde::de::Error::custom("scalar was not canonically encoded"))
-            }
-        }
-
-        deserializer.deserialize_tuple(32, ScalarVisitor)
+        let bytes = crate::encoding::deserialize_bytes(deserializer)?;
+        Option::from(Scalar::from_canonical_bytes(bytes))
+            .ok_or_else(|| serde::de::Error::custom("scalar was not canonically encoded"))
     }
 }
 
@@ -552,6 +761,38 @@ impl From<u128> for Scalar {
     }
 }
 
+#[cfg(feature = "crypto-bigint")]
+impl From<&Scalar> for crypto_bigint::U256 {
+    fn from(s: &Scalar) -> crypto_bigint::U256 {
+        use crypto_bigint::Encoding;
+        crypto_bigint::U256::from_le_bytes(s.bytes)
+    }
+}
+
+#[cfg(feature = "crypto-bigint")]
+impl From<Scalar> for crypto_bigint::U256 {
+    fn from(s: Scalar) -> crypto_bigint::U256 {
+        crypto_bigint::U256::from(&s)
+    }
+}
+
+#[cfg(feature = "crypto-bigint")]
+impl From<&Scalar> for crypto_bigint::U512 {
+    fn from(s: &Scalar) -> crypto_bigint::U512 {
+        use crypto_bigint::Encoding;
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&s.bytes);
+        crypto_bigint::U512::from_le_bytes(bytes)
+    }
+}
+
+#[cfg(feature = "crypto-bigint")]
+impl From<Scalar> for crypto_bigint::U512 {
+    fn from(s: Scalar) -> crypto_bigint::U512 {
+        crypto_bigint::U512::from(&s)
+    }
+}
+
 #[cfg(feature = "zeroize")]
 impl Zeroize for Scalar {
     fn zeroize(&mut self) {
@@ -600,6 +841,110 @@ impl Scalar {
         Scalar::from_bytes_mod_order_wide(&scalar_bytes)
     }
 
+    #[cfg(feature = "rand_core_0_9")]
+    /// Return a `Scalar` chosen uniformly at random using an RNG
+    /// implementing `rand_core` 0.9's `CryptoRng`.
+    ///
+    /// This is [`Scalar::random`] for the `rand_core` 0.9 traits, and
+    /// gives the same output for the same random bytes.
+    pub fn random_0_9<R: rand_core_0_9::CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        let mut scalar_bytes = [0u8; 64];
+        rng.fill_bytes(&mut scalar_bytes);
+        Scalar::from_bytes_mod_order_wide(&scalar_bytes)
+    }
+
+    #[cfg(feature = "rand_core_0_9")]
+    /// Return a `Scalar` chosen uniformly at random using a fallible RNG
+    /// implementing `rand_core` 0.9's `TryCryptoRng`, or the RNG's error.
+    pub fn try_random_0_9<R: rand_core_0_9::TryCryptoRng + ?Sized>(
+        rng: &mut R,
+    ) -> Result<Self, R::Error> {
+        let mut scalar_bytes = [0u8; 64];
+        rng.try_fill_bytes(&mut scalar_bytes)?;
+        Ok(Scalar::from_bytes_mod_order_wide(&scalar_bytes))
+    }
+
+    #[cfg(any(test, feature = "rand_core"))]
+    /// Return a nonzero `Scalar` chosen uniformly at random using a
+    /// user-provided RNG.
+    ///
+    /// This samples with [`Scalar::random`] and retries on zero.  A retry
+    /// happens with probability about \\(2\^{-252}\\), so in practice this
+    /// runs in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// use rand_core::OsRng;
+    ///
+    /// let nonce = Scalar::random_nonzero(&mut OsRng);
+    /// assert_ne!(nonce, Scalar::ZERO);
+    /// # }
+    /// ```
+    pub fn random_nonzero<R: CryptoRngCore + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let candidate = Scalar::random(rng);
+            if !bool::from(candidate.ct_eq(&Scalar::ZERO)) {
+                return candidate;
+            }
+        }
+    }
+
+    #[cfg(any(test, feature = "rand_core"))]
+    /// Return a `Scalar` chosen uniformly at random from
+    /// \\([0, \mathrm{bound})\\) using a user-provided RNG.
+    ///
+    /// This uses rejection sampling: each attempt draws as many random
+    /// bits as `bound - 1` has, and is accepted if the result is less than
+    /// `bound`, which happens with probability at least one half.  The
+    /// number of attempts is independent of the value returned, so the
+    /// running time reveals nothing about it; it does depend on `bound`,
+    /// which is treated as public.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() {
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// use rand_core::OsRng;
+    /// use subtle::ConstantTimeLess;
+    ///
+    /// let bound = Scalar::from(1000u64);
+    /// let x = Scalar::random_below(&bound, &mut OsRng);
+    /// assert!(bool::from(x.ct_lt(&bound)));
+    /// # }
+    /// ```
+    pub fn random_below<R: CryptoRngCore + ?Sized>(bound: &Scalar, rng: &mut R) -> Self {
+        assert!(bound != &Scalar::ZERO, "bound must be nonzero");
+
+        // The number of bits needed to represent bound - 1.
+        let max = bound - Scalar::ONE;
+        let bits = match max.bytes.iter().rposition(|&b| b != 0) {
+            Some(i) => 8 * i + 8 - max.bytes[i].leading_zeros() as usize,
+            None => 0,
+        };
+
+        loop {
+            let mut candidate = Scalar { bytes: [0u8; 32] };
+            rng.fill_bytes(&mut candidate.bytes);
+            for (i, byte) in candidate.bytes.iter_mut().enumerate() {
+                let keep = bits.saturating_sub(8 * i).min(8);
+                *byte &= ((1u16 << keep) - 1) as u8;
+            }
+            if candidate.ct_lt(bound).into() {
+                return candidate;
+            }
+        }
+    }
+
     #[cfg(feature = "digest")]
     /// Hash a slice of bytes into a scalar.
     ///
@@ -677,6 +1022,70 @@ impl Scalar {
         Scalar::from_bytes_mod_order_wide(&output)
     }
 
+    #[cfg(feature = "digest")]
+    /// Hash a slice of bytes into a scalar, using an extendable-output
+    /// function.
+    ///
+    /// Takes a type parameter `X`, which is any extendable-output function
+    /// such as SHAKE128 or SHAKE256.  64 bytes of output are read and
+    /// reduced modulo \\(\ell\\).
+    ///
+    /// Convenience wrapper around `from_xof`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// use sha3::Shake256;
+    ///
+    /// # fn main() {
+    /// let msg = "To really appreciate architecture, you may even need to commit a murder";
+    /// let s = Scalar::hash_from_bytes_xof::<Shake256>(msg.as_bytes());
+    /// # }
+    /// ```
+    pub fn hash_from_bytes_xof<X>(input: &[u8]) -> Scalar
+    where
+        X: ExtendableOutput + Update + Default,
+    {
+        let mut xof = X::default();
+        xof.update(input);
+        Scalar::from_xof(&mut xof.finalize_xof())
+    }
+
+    #[cfg(feature = "digest")]
+    /// Construct a scalar by reading 64 bytes from an extendable-output
+    /// function's reader, and reducing them modulo \\(\ell\\).
+    ///
+    /// Use this instead of `hash_from_bytes_xof` to derive several
+    /// scalars from a single XOF stream, as Fiat–Shamir transcripts often
+    /// do: each call consumes the next 64 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// use sha3::digest::{ExtendableOutput, Update};
+    /// use sha3::Shake128;
+    ///
+    /// # fn main() {
+    /// let mut xof = Shake128::default();
+    /// xof.update(b"transcript");
+    /// let mut reader = xof.finalize_xof();
+    ///
+    /// let c_0 = Scalar::from_xof(&mut reader);
+    /// let c_1 = Scalar::from_xof(&mut reader);
+    /// assert_ne!(c_0, c_1);
+    /// # }
+    /// ```
+    pub fn from_xof<R>(reader: &mut R) -> Scalar
+    where
+        R: XofReader + ?Sized,
+    {
+        let mut output = [0u8; 64];
+        reader.read(&mut output);
+        Scalar::from_bytes_mod_order_wide(&output)
+    }
+
     /// Convert this `Scalar` to its underlying sequence of bytes.
     ///
     /// # Example
@@ -707,6 +1116,28 @@ impl Scalar {
         &self.bytes
     }
 
+    /// Convert this `Scalar` to the big-endian byte encoding of its
+    /// integer representative.
+    ///
+    /// This is the byte-reversal of [`Scalar::to_bytes`], for interoperating
+    /// with formats that fix a big-endian integer encoding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve25519_dalek::scalar::Scalar;
+    ///
+    /// let s = Scalar::from(0x0102u64);
+    /// let bytes = s.to_bytes_be();
+    ///
+    /// assert_eq!(&bytes[30..], &[0x01, 0x02]);
+    /// ```
+    pub fn to_bytes_be(&self) -> [u8; 32] {
+        let mut bytes = self.bytes;
+        bytes.reverse();
+        bytes
+    }
+
     /// Given a nonzero `Scalar`, compute its multiplicative inverse.
     ///
     /// # Warning
@@ -748,6 +1179,216 @@ impl Scalar {
         self.unpack().invert().pack()
     }
 
+    /// Compute a square root of this `Scalar` modulo \\(\ell\\), if one
+    /// exists.
+    ///
+    /// Of the two roots \\(\pm x\\), the one whose canonical encoding is even
+    /// (has its low bit clear) is returned, so the result is deterministic.
+    /// Returns `None` if this `Scalar` is not a square.
+    ///
+    /// This function runs in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let x = Scalar::from(9u64);
+    /// let root = x.sqrt().unwrap();
+    /// assert_eq!(root * root, x);
+    ///
+    /// // 2 is not a square modulo the group order.
+    /// assert!(bool::from(Scalar::from(2u64).sqrt().is_none()));
+    /// ```
+    pub fn sqrt(&self) -> CtOption<Scalar> {
+        // Since l = 5 (mod 8), we can use Atkin's algorithm: with
+        // b = (2a)^((l-5)/8) and i = 2ab^2, which satisfies i^2 = -1 when a
+        // is a nonzero square, the root is ab(i - 1).
+        let two_a = self + self;
+        let b = two_a.pow_vartime(&[
+            0xcb02_4c63_4b9e_ba7d,
+            0x029b_df3b_d45e_f39a,
+            0x0000_0000_0000_0000,
+            0x0200_0000_0000_0000,
+        ]);
+        let i = two_a * b * b;
+        let mut x = self * b * (i - Scalar::ONE);
+
+        let x_is_odd = Choice::from(x.bytes[0] & 1);
+        x.conditional_negate(x_is_odd);
+
+        let is_square = (x * x).ct_eq(self);
+        CtOption::new(x, is_square)
+    }
+
+    /// Compute the Legendre symbol \\( (a / \ell) \\) of this `Scalar`
+    /// \\(a\\).
+    ///
+    /// Returns \\(0\\) if this `Scalar` is zero, \\(1\\) if it is a nonzero
+    /// square, and \\(-1\\) otherwise.
+    ///
+    /// This function runs in constant time.
+    pub fn legendre(&self) -> i8 {
+        // Euler's criterion: a^((l-1)/2) is 0, 1 or -1.
+        let euler = self.pow_vartime(&[
+            0x2c09_318d_2e7a_e9f6,
+            0x0a6f_7cef_517b_ce6b,
+            0x0000_0000_0000_0000,
+            0x0800_0000_0000_0000,
+        ]);
+
+        let mut symbol = -1i8;
+        symbol.conditional_assign(&1, euler.ct_eq(&Scalar::ONE));
+        symbol.conditional_assign(&0, euler.ct_eq(&Scalar::ZERO));
+        symbol
+    }
+
+    /// Raise this `Scalar` to the power `exp`, given as a 256-bit integer
+    /// in little-endian 64-bit limbs.
+    ///
+    /// The exponent is used as-is, without reduction modulo
+    /// \\(\ell - 1\\).
+    ///
+    /// This function runs in constant time with respect to both `self`
+    /// and `exp`.  If the exponent is public, [`Scalar::pow_vartime`] is
+    /// faster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let x = Scalar::from(3u64);
+    /// assert_eq!(x.pow(&[4, 0, 0, 0]), Scalar::from(81u64));
+    ///
+    /// // Fermat's little theorem: x^(l-1) = 1.
+    /// let l_minus_one = [
+    ///     0x5812_631a_5cf5_d3ec,
+    ///     0x14de_f9de_a2f7_9cd6,
+    ///     0x0000_0000_0000_0000,
+    ///     0x1000_0000_0000_0000,
+    /// ];
+    /// assert_eq!(x.pow(&l_minus_one), Scalar::ONE);
+    /// ```
+    pub fn pow(&self, exp: &[u64; 4]) -> Scalar {
+        // Fixed 4-bit windows, with a constant-time table lookup for each
+        // window so that the sequence of operations is independent of exp.
+        let base = self.unpack().as_montgomery();
+        let mut table = [constants::R; 16];
+        for i in 1..16 {
+            table[i] = UnpackedScalar::montgomery_mul(&table[i - 1], &base);
+        }
+
+        let mut acc = constants::R;
+        for limb in exp.iter().rev() {
+            for shift in (0..16).rev().map(|j| 4 * j) {
+                for _ in 0..4 {
+                    acc = acc.montgomery_square();
+                }
+
+                let digit = ((limb >> shift) & 0xf) as u8;
+                let mut entry = constants::R;
+                for (j, table_entry) in table.iter().enumerate() {
+                    entry.conditional_assign(table_entry, digit.ct_eq(&(j as u8)));
+                }
+                acc = UnpackedScalar::montgomery_mul(&acc, &entry);
+            }
+        }
+
+        acc.from_montgomery().pack()
+    }
+
+    /// Raise this `Scalar` to the power `exp`, given as a 256-bit integer
+    /// in little-endian 64-bit limbs.
+    ///
+    /// This runs in variable time with respect to `exp`, but in constant
+    /// time with respect to `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let x = Scalar::from(3u64);
+    /// assert_eq!(x.pow_vartime(&[4, 0, 0, 0]), Scalar::from(81u64));
+    /// ```
+    pub fn pow_vartime(&self, exp: &[u64; 4]) -> Scalar {
+        let base = self.unpack().as_montgomery();
+        let mut acc = constants::R;
+
+        for limb in exp.iter().rev() {
+            for i in (0..64).rev() {
+                acc = acc.montgomery_square();
+                if (limb >> i) & 1 == 1 {
+                    acc = UnpackedScalar::montgomery_mul(&acc, &base);
+                }
+            }
+        }
+
+        acc.from_montgomery().pack()
+    }
+
+    /// Compute `self * b + c`.
+    ///
+    /// The addition is folded into the final reduction of the
+    /// multiplication, so the result is only reduced once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let a = Scalar::from(3u64);
+    /// let b = Scalar::from(5u64);
+    /// let c = Scalar::from(7u64);
+    /// assert_eq!(a.mul_add(&b, &c), Scalar::from(22u64));
+    /// ```
+    pub fn mul_add(&self, b: &Scalar, c: &Scalar) -> Scalar {
+        UnpackedScalar::mul_add(&self.unpack(), &b.unpack(), &c.unpack()).pack()
+    }
+
+    /// Compute \\( x / 2 \bmod \ell \\), where \\( x \\) is this `Scalar`.
+    ///
+    /// This is a single multiplication by the precomputed inverse of 2,
+    /// and runs in constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let x = Scalar::from(7u64);
+    /// assert_eq!(x.halve() + x.halve(), x);
+    /// ```
+    pub fn halve(&self) -> Scalar {
+        self * INV_TWO
+    }
+
+    /// Compute \\( x / 2\^k \bmod \ell \\), where \\( x \\) is this `Scalar`.
+    ///
+    /// The inverse of \\( 2\^k \\) is computed as a power of the precomputed
+    /// inverse of 2, which costs at most 64 multiplications, far fewer than
+    /// a general [`Scalar::invert`].
+    ///
+    /// This runs in variable time with respect to `k`, but in constant time
+    /// with respect to `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let x = Scalar::from(5u64);
+    /// assert_eq!(x.div_pow2(10) * Scalar::from(1024u64), x);
+    /// ```
+    pub fn div_pow2(&self, k: u32) -> Scalar {
+        let base = INV_TWO.unpack().as_montgomery();
+        let mut acc = constants::R;
+
+        for i in (0..(32 - k.leading_zeros())).rev() {
+            acc = acc.montgomery_square();
+            if (k >> i) & 1 == 1 {
+                acc = UnpackedScalar::montgomery_mul(&acc, &base);
+            }
+        }
+
+        UnpackedScalar::montgomery_mul(&acc, &self.unpack()).pack()
+    }
+
     /// Given a slice of nonzero (possibly secret) `Scalar`s,
     /// compute their inverses in a batch.
     ///
@@ -836,8 +1477,131 @@ impl Scalar {
         ret
     }
 
-    /// Get the bits of the scalar, in little-endian order
-    pub(crate) fn bits_le(&self) -> impl DoubleEndedIterator<Item = bool> + '_ {
+    /// Given an array of nonzero (possibly secret) `Scalar`s, compute
+    /// their inverses in a batch, without allocating.
+    ///
+    /// This is [`Scalar::batch_invert`] with its scratch space on the
+    /// stack, for targets without the `alloc` feature.
+    ///
+    /// # Return
+    ///
+    /// Each element of `inputs` is replaced by its inverse.
+    ///
+    /// The product of all inverses is returned.
+    ///
+    /// # Warning
+    ///
+    /// All input `Scalars` **MUST** be nonzero.  If you cannot
+    /// *prove* that this is the case, you **SHOULD NOT USE THIS
+    /// FUNCTION**.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// # fn main() {
+    /// let mut scalars = [Scalar::from(3u64), Scalar::from(5u64)];
+    ///
+    /// let allinv = Scalar::batch_invert_array(&mut scalars);
+    ///
+    /// assert_eq!(allinv, Scalar::from(15u64).invert());
+    /// assert_eq!(scalars[0], Scalar::from(3u64).invert());
+    /// assert_eq!(scalars[1], Scalar::from(5u64).invert());
+    /// # }
+    /// ```
+    pub fn batch_invert_array<const N: usize>(inputs: &mut [Scalar; N]) -> Scalar {
+        let mut scratch = [Scalar::ONE; N];
+        Scalar::batch_invert_with_scratch(inputs, &mut scratch)
+    }
+
+    /// Given a slice of nonzero (possibly secret) `Scalar`s, compute
+    /// their inverses in a batch, using the caller-provided `scratch`
+    /// space instead of allocating.
+    ///
+    /// `scratch` must be at least as long as `inputs`; its contents on
+    /// entry are ignored, and are zeroized on exit when the `zeroize`
+    /// feature is enabled.
+    ///
+    /// # Return
+    ///
+    /// Each element of `inputs` is replaced by its inverse.
+    ///
+    /// The product of all inverses is returned.
+    ///
+    /// # Warning
+    ///
+    /// All input `Scalars` **MUST** be nonzero.  If you cannot
+    /// *prove* that this is the case, you **SHOULD NOT USE THIS
+    /// FUNCTION**.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scratch` is shorter than `inputs`.
+    pub fn batch_invert_with_scratch(inputs: &mut [Scalar], scratch: &mut [Scalar]) -> Scalar {
+        // This code is essentially identical to the FieldElement
+        // implementation, and is documented there.  Unfortunately,
+        // it's not easy to write it generically, since here we want
+        // to use `UnpackedScalar`s internally, and `Scalar`s
+        // externally, but there's no corresponding distinction for
+        // field elements.
+
+        let n = inputs.len();
+        let scratch = &mut scratch[..n];
+
+        // Keep an accumulator of all of the previous products
+        let mut acc = Scalar::ONE.unpack().as_montgomery();
+
+        // Pass through the input vector, recording the previous
+        // products in the scratch space (packed, but still in
+        // Montgomery form)
+        for (input, scratch) in inputs.iter_mut().zip(scratch.iter_mut()) {
+            *scratch = acc.pack();
+
+            // Avoid unnecessary Montgomery multiplication in second pass by
+            // keeping inputs in Montgomery form
+            let tmp = input.unpack().as_montgomery();
+            *input = tmp.pack();
+            acc = UnpackedScalar::montgomery_mul(&acc, &tmp);
+        }
+
+        // acc is nonzero iff all inputs are nonzero
+        debug_assert!(acc.pack() != Scalar::ZERO);
+
+        // Compute the inverse of all products
+        acc = acc.montgomery_invert().from_montgomery();
+
+        // We need to return the product of all inverses later
+        let ret = acc.pack();
+
+        // Pass through the vector backwards to compute the inverses
+        // in place
+        for (input, scratch) in inputs.iter_mut().rev().zip(scratch.iter().rev()) {
+            let tmp = UnpackedScalar::montgomery_mul(&acc, &input.unpack());
+            *input = UnpackedScalar::montgomery_mul(&acc, &scratch.unpack()).pack();
+            acc = tmp;
+        }
+
+        #[cfg(feature = "zeroize")]
+        scratch.iter_mut().for_each(Zeroize::zeroize);
+
+        ret
+    }
+
+    /// Get the bits of the scalar, in little-endian order.
+    ///
+    /// The iterator yields all 256 bits of the encoding, least significant
+    /// first; for a reduced scalar the top three are always zero.  Reading
+    /// the bits does not branch on them, but what the caller does with
+    /// them may.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let bits: Vec<bool> = Scalar::from(6u64).bits_le().take(4).collect();
+    /// assert_eq!(bits, [false, true, true, false]);
+    /// ```
+    pub fn bits_le(&self) -> impl DoubleEndedIterator<Item = bool> + '_ {
         (0..256).map(|i| {
             // As i runs from 0..256, the bottom 3 bits index the bit, while the upper bits index
             // the byte. Since self.bytes is little-endian at the byte level, this iterator is
@@ -846,6 +1610,14 @@ impl Scalar {
         })
     }
 
+    /// Get the bits of the scalar, in big-endian order.
+    ///
+    /// This is [`Scalar::bits_le`] reversed, as used by left-to-right
+    /// algorithms such as the Montgomery ladder.
+    pub fn bits_be(&self) -> impl DoubleEndedIterator<Item = bool> + '_ {
+        self.bits_le().rev()
+    }
+
     /// Compute a width-\\(w\\) "Non-Adjacent Form" of this scalar.
     ///
     /// A width-\\(w\\) NAF of a positive integer \\(k\\) is an expression
@@ -873,6 +1645,38 @@ impl Scalar {
     /// in half, and using odd digits cuts the table size in half
     /// again.
     ///
+    /// # Panics
+    ///
+    /// Panics unless \\(2 \leq w \leq 8\\).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let x = Scalar::from(0b1011_0111u64);
+    /// let naf = x.non_adjacent_form(3);
+    ///
+    /// // Nonzero digits are odd, at most 3 in magnitude, and reconstruct x.
+    /// assert_eq!(&naf[..8], &[-1, 0, 0, -1, 0, 0, 3, 0]);
+    /// assert!(naf[8..].iter().all(|&d| d == 0));
+    /// ```
+    #[cfg(feature = "hazmat")]
+    pub fn non_adjacent_form(&self, w: usize) -> [i8; 256] {
+        assert!((2..=8).contains(&w), "NAF width must be between 2 and 8");
+        self.non_adjacent_form_unchecked(w)
+    }
+
+    /// Compute a width-\\(w\\) "Non-Adjacent Form" of this scalar.
+    ///
+    /// See the `hazmat` version of this function for documentation.
+    #[cfg(not(feature = "hazmat"))]
+    pub(crate) fn non_adjacent_form(&self, w: usize) -> [i8; 256] {
+        self.non_adjacent_form_unchecked(w)
+    }
+
+    /// Compute a width-\\(w\\) "Non-Adjacent Form" of this scalar, for
+    /// \\(2 \leq w \leq 8\\), which the caller must ensure.
+    ///
     /// To compute a \\(w\\)-NAF, we use a modification of Algorithm 3.35 of HMV:
     ///
     /// 1. \\( i \gets 0 \\)
@@ -918,7 +1722,7 @@ impl Scalar {
     /// If \\( k \mod 2^w\\) is even, we emit \\(0\\), advance 1 bit
     /// and reindex.  In fact, by setting all digits to \\(0\\)
     /// initially, we don't need to emit anything.
-    pub(crate) fn non_adjacent_form(&self, w: usize) -> [i8; 256] {
+    fn non_adjacent_form_unchecked(&self, w: usize) -> [i8; 256] {
         // required by the NAF definition
         debug_assert!(w >= 2);
         // required so that the NAF digits fit in i8
@@ -1017,7 +1821,7 @@ impl Scalar {
     }
 
     /// Returns a size hint indicating how many entries of the return
-    /// value of `to_radix_2w` are nonzero.
+    /// value of `as_radix_2w` are nonzero.
     #[cfg(any(feature = "alloc", all(test, feature = "precomputed-tables")))]
     pub(crate) fn to_radix_2w_size_hint(w: usize) -> usize {
         debug_assert!(w >= 4);
@@ -1025,7 +1829,7 @@ impl Scalar {
 
         let digits_count = match w {
             4..=7 => (256 + w - 1) / w,
-            // See comment in to_radix_2w on handling the terminal carry.
+            // See comment in as_radix_2w on handling the terminal carry.
             8 => (256 + w - 1) / w + 1_usize,
             _ => panic!("invalid radix parameter"),
         };
@@ -1055,8 +1859,33 @@ impl Scalar {
     /// $$
     /// with \\(-2\^w/2 \leq a_i < 2\^w/2\\) for \\(0 \leq i < (n-1)\\) and \\(-2\^w/2 \leq a_{n-1} \leq 2\^w/2\\).
     ///
-    #[cfg(any(feature = "alloc", feature = "precomputed-tables"))]
-    pub(crate) fn as_radix_2w(&self, w: usize) -> [i8; 64] {
+    /// This function runs in constant time with respect to the scalar.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless \\(4 \leq w \leq 8\\).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use curve25519_dalek::scalar::Scalar;
+    /// let x = Scalar::from(1000u64);
+    /// let digits = x.as_radix_2w(5);
+    ///
+    /// // 1000 = 8 + 31 * 32, recentered to 8 - 1 * 32 + 1 * 32^2.
+    /// assert_eq!(&digits[..4], &[8, -1, 1, 0]);
+    /// ```
+    pub fn as_radix_2w(&self, w: usize) -> [i8; 64] {
+        assert!(
+            (4..=8).contains(&w),
+            "radix exponent must be between 4 and 8"
+        );
+        self.as_radix_2w_unchecked(w)
+    }
+
+    /// Creates a representation of a Scalar in radix \\( 2^w \\), for
+    /// \\(4 \leq w \leq 8\\), which the caller must ensure.
+    fn as_radix_2w_unchecked(&self, w: usize) -> [i8; 64] {
         debug_assert!(w >= 4);
         debug_assert!(w <= 8);
 
@@ -1129,9 +1958,13 @@ impl Scalar {
         x_mod_l.pack()
     }
 
-    /// Check whether this `Scalar` is the canonical representative mod \\(\ell\\). This is not
-    /// public because any `Scalar` that is publicly observed is reduced, by scalar invariant #2.
-    fn is_canonical(&self) -> Choice {
+    /// Check whether this `Scalar` is the canonical representative mod \\(\ell\\), i.e. whether
+    /// its stored encoding is reduced.
+    ///
+    /// By scalar invariant #2 this holds for every `Scalar` produced by this crate's arithmetic
+    /// and constructors, except those built with the deprecated `Scalar::from_bits` under the
+    /// `legacy_compatibility` feature.  This runs in constant time.
+    pub fn is_canonical(&self) -> Choice {
         self.ct_eq(&self.reduce())
     }
 }
@@ -1208,6 +2041,206 @@ impl UnpackedScalar {
     }
 }
 
+/// A `Scalar` held in Montgomery form, for long runs of multiplications.
+///
+/// Every multiplication of `Scalar`s converts both operands into
+/// Montgomery form and the product back out of it.  A `MontgomeryScalar`
+/// stays in Montgomery form, so each multiplication is a single Montgomery
+/// multiplication, and the conversions are paid only when entering and
+/// leaving with [`MontgomeryScalar::from`] and [`MontgomeryScalar::to_scalar`].
+/// This pays off in multiplication-heavy code such as polynomial
+/// evaluation or the computation of many Lagrange coefficients.
+///
+/// All operations run in constant time.
+///
+/// # Example
+///
+/// ```
+/// use curve25519_dalek::scalar::{MontgomeryScalar, Scalar};
+///
+/// // Evaluate 3 + 2x + x^2 at x = 5 with Horner's rule.
+/// let coefficients = [Scalar::from(3u64), Scalar::from(2u64), Scalar::ONE];
+/// let x = MontgomeryScalar::from(Scalar::from(5u64));
+///
+/// let y = coefficients
+///     .iter()
+///     .rev()
+///     .fold(MontgomeryScalar::ZERO, |acc, a| acc * x + MontgomeryScalar::from(a));
+///
+/// assert_eq!(y.to_scalar(), Scalar::from(38u64));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct MontgomeryScalar(UnpackedScalar);
+
+impl MontgomeryScalar {
+    /// The scalar \\( 0 \\), in Montgomery form.
+    pub const ZERO: Self = MontgomeryScalar(UnpackedScalar::ZERO);
+
+    /// The scalar \\( 1 \\), in Montgomery form.
+    pub const ONE: Self = MontgomeryScalar(constants::R);
+
+    /// Convert this value back to a `Scalar`.
+    pub fn to_scalar(&self) -> Scalar {
+        self.0.from_montgomery().pack()
+    }
+
+    /// Compute the square of this value.
+    pub fn square(&self) -> MontgomeryScalar {
+        MontgomeryScalar(self.0.montgomery_square())
+    }
+
+    /// Compute the multiplicative inverse of this value.
+    ///
+    /// # Warning
+    ///
+    /// As with [`Scalar::invert`], the result is zero if this value is
+    /// zero.
+    pub fn invert(&self) -> MontgomeryScalar {
+        MontgomeryScalar(self.0.montgomery_invert())
+    }
+}
+
+impl From<Scalar> for MontgomeryScalar {
+    fn from(s: Scalar) -> MontgomeryScalar {
+        MontgomeryScalar::from(&s)
+    }
+}
+
+impl<'a> From<&'a Scalar> for MontgomeryScalar {
+    fn from(s: &'a Scalar) -> MontgomeryScalar {
+        MontgomeryScalar(s.unpack().as_montgomery())
+    }
+}
+
+impl From<MontgomeryScalar> for Scalar {
+    fn from(s: MontgomeryScalar) -> Scalar {
+        s.to_scalar()
+    }
+}
+
+impl Eq for MontgomeryScalar {}
+impl PartialEq for MontgomeryScalar {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl ConstantTimeEq for MontgomeryScalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // The limbs are always fully reduced, so the encodings are unique.
+        self.0.as_bytes().ct_eq(&other.0.as_bytes())
+    }
+}
+
+impl<'b> MulAssign<&'b MontgomeryScalar> for MontgomeryScalar {
+    fn mul_assign(&mut self, rhs: &'b MontgomeryScalar) {
+        *self = *self * rhs;
+    }
+}
+
+define_mul_assign_variants!(LHS = MontgomeryScalar, RHS = MontgomeryScalar);
+
+impl<'a, 'b> Mul<&'b MontgomeryScalar> for &'a MontgomeryScalar {
+    type Output = MontgomeryScalar;
+    fn mul(self, rhs: &'b MontgomeryScalar) -> MontgomeryScalar {
+        MontgomeryScalar(UnpackedScalar::montgomery_mul(&self.0, &rhs.0))
+    }
+}
+
+define_mul_variants!(
+    LHS = MontgomeryScalar,
+    RHS = MontgomeryScalar,
+    Output = MontgomeryScalar
+);
+
+impl<'b> AddAssign<&'b MontgomeryScalar> for MontgomeryScalar {
+    fn add_assign(&mut self, rhs: &'b MontgomeryScalar) {
+        *self = *self + rhs;
+    }
+}
+
+define_add_assign_variants!(LHS = MontgomeryScalar, RHS = MontgomeryScalar);
+
+impl<'a, 'b> Add<&'b MontgomeryScalar> for &'a MontgomeryScalar {
+    type Output = MontgomeryScalar;
+    fn add(self, rhs: &'b MontgomeryScalar) -> MontgomeryScalar {
+        // Addition is linear, so it commutes with the Montgomery factor.
+        MontgomeryScalar(UnpackedScalar::add(&self.0, &rhs.0))
+    }
+}
+
+define_add_variants!(
+    LHS = MontgomeryScalar,
+    RHS = MontgomeryScalar,
+    Output = MontgomeryScalar
+);
+
+impl<'b> SubAssign<&'b MontgomeryScalar> for MontgomeryScalar {
+    fn sub_assign(&mut self, rhs: &'b MontgomeryScalar) {
+        *self = *self - rhs;
+    }
+}
+
+define_sub_assign_variants!(LHS = MontgomeryScalar, RHS = MontgomeryScalar);
+
+impl<'a, 'b> Sub<&'b MontgomeryScalar> for &'a MontgomeryScalar {
+    type Output = MontgomeryScalar;
+    fn sub(self, rhs: &'b MontgomeryScalar) -> MontgomeryScalar {
+        MontgomeryScalar(UnpackedScalar::sub(&self.0, &rhs.0))
+    }
+}
+
+define_sub_variants!(
+    LHS = MontgomeryScalar,
+    RHS = MontgomeryScalar,
+    Output = MontgomeryScalar
+);
+
+impl<'a> Neg for &'a MontgomeryScalar {
+    type Output = MontgomeryScalar;
+    fn neg(self) -> MontgomeryScalar {
+        MontgomeryScalar(UnpackedScalar::sub(&UnpackedScalar::ZERO, &self.0))
+    }
+}
+
+impl Neg for MontgomeryScalar {
+    type Output = MontgomeryScalar;
+    fn neg(self) -> MontgomeryScalar {
+        -&self
+    }
+}
+
+impl<T> Product<T> for MontgomeryScalar
+where
+    T: Borrow<MontgomeryScalar>,
+{
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(MontgomeryScalar::ONE, |acc, item| acc * item.borrow())
+    }
+}
+
+impl<T> Sum<T> for MontgomeryScalar
+where
+    T: Borrow<MontgomeryScalar>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(MontgomeryScalar::ZERO, |acc, item| acc + item.borrow())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for MontgomeryScalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
 #[cfg(feature = "group")]
 impl Field for Scalar {
     const ZERO: Self = Self::ZERO;
@@ -1236,16 +2269,8 @@ impl Field for Scalar {
         group::ff::helpers::sqrt_ratio_generic(num, div)
     }
 
-    fn sqrt(&self) -> CtOption<Self> {
-        group::ff::helpers::sqrt_tonelli_shanks(
-            self,
-            [
-                0xcb02_4c63_4b9e_ba7d,
-                0x029b_df3b_d45e_f39a,
-                0x0000_0000_0000_0000,
-                0x0200_0000_0000_0000,
-            ],
-        )
+    fn sqrt(&self) -> CtOption<Self> {
+        Scalar::sqrt(self)
     }
 }
 
@@ -1285,13 +2310,7 @@ impl PrimeField for Scalar {
     const NUM_BITS: u32 = 253;
     const CAPACITY: u32 = 252;
 
-    const TWO_INV: Self = Self {
-        bytes: [
-            0xf7, 0xe9, 0x7a, 0x2e, 0x8d, 0x31, 0x09, 0x2c, 0x6b, 0xce, 0x7b, 0x51, 0xef, 0x7c,
-            0x6f, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x08,
-        ],
-    };
+    const TWO_INV: Self = INV_TWO;
     const MULTIPLICATIVE_GENERATOR: Self = Self {
         bytes: [
             2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -1341,6 +2360,84 @@ impl FromUniformBytes<64> for Scalar {
     }
 }
 
+// The `num-traits` impls are written with fully qualified trait paths, since
+// `num_traits::Zero::is_zero` would otherwise clash with `ff::Field::is_zero`.
+
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for Scalar {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.ct_eq(&Self::ZERO).into()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::One for Scalar {
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        self.ct_eq(&Self::ONE).into()
+    }
+}
+
+/// Inversion modulo \\( \ell \\), following [`Scalar::invert`]: the
+/// inverse of zero is zero.
+#[cfg(feature = "num-traits")]
+impl num_traits::Inv for Scalar {
+    type Output = Scalar;
+
+    fn inv(self) -> Scalar {
+        self.invert()
+    }
+}
+
+/// Inversion modulo \\( \ell \\), following [`Scalar::invert`]: the
+/// inverse of zero is zero.
+#[cfg(feature = "num-traits")]
+impl num_traits::Inv for &Scalar {
+    type Output = Scalar;
+
+    fn inv(self) -> Scalar {
+        self.invert()
+    }
+}
+
+// Arithmetic modulo \( \ell \) cannot overflow, so the checked operations
+// always succeed.
+
+#[cfg(feature = "num-traits")]
+impl num_traits::CheckedAdd for Scalar {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Some(self + v)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::CheckedSub for Scalar {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Some(self - v)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::CheckedMul for Scalar {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Some(self * v)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::CheckedNeg for Scalar {
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
 /// Read one or more u64s stored as little endian bytes.
 ///
 /// ## Panics
@@ -1559,6 +2656,19 @@ pub(crate) mod test {
         }
     }
 
+    #[test]
+    #[cfg(feature = "hazmat")]
+    #[should_panic(expected = "NAF width must be between 2 and 8")]
+    fn non_adjacent_form_rejects_wide_window() {
+        Scalar::ONE.non_adjacent_form(9);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix exponent must be between 4 and 8")]
+    fn as_radix_2w_rejects_narrow_radix() {
+        Scalar::ONE.as_radix_2w(3);
+    }
+
     #[test]
     fn from_u64() {
         let val: u64 = 0xdeadbeefdeadbeef;
@@ -1853,6 +2963,409 @@ pub(crate) mod test {
         }
     }
 
+    #[test]
+    fn montgomery_scalar_arithmetic() {
+        let mut rng = rand::thread_rng();
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+        let (a_m, b_m) = (MontgomeryScalar::from(a), MontgomeryScalar::from(b));
+
+        assert_eq!(a_m.to_scalar(), a);
+        assert_eq!((a_m * b_m).to_scalar(), a * b);
+        assert_eq!((a_m + b_m).to_scalar(), a + b);
+        assert_eq!((a_m - b_m).to_scalar(), a - b);
+        assert_eq!((-a_m).to_scalar(), -a);
+        assert_eq!(a_m.square().to_scalar(), a * a);
+        assert_eq!(a_m.invert().to_scalar(), a.invert());
+        assert_eq!(a_m * a_m.invert(), MontgomeryScalar::ONE);
+        assert_eq!(MontgomeryScalar::ONE.to_scalar(), Scalar::ONE);
+        assert_eq!(MontgomeryScalar::ZERO.to_scalar(), Scalar::ZERO);
+
+        // A long chain of multiplications agrees with `Scalar`.
+        let mut acc = Scalar::ONE;
+        let mut acc_m = MontgomeryScalar::ONE;
+        for _ in 0..100 {
+            acc *= a;
+            acc += b;
+            acc_m *= a_m;
+            acc_m += b_m;
+        }
+        assert_eq!(Scalar::from(acc_m), acc);
+
+        let xs = [a_m, b_m, a_m + b_m];
+        let product: MontgomeryScalar = xs.iter().product();
+        let sum: MontgomeryScalar = xs.into_iter().sum();
+        assert_eq!(product.to_scalar(), a * b * (a + b));
+        assert_eq!(sum.to_scalar(), a + b + a + b);
+    }
+
+    #[test]
+    fn batch_invert_without_alloc() {
+        let mut inputs = [Scalar::from(3u64); 16];
+        for i in 1..16 {
+            inputs[i] = inputs[i - 1] * Scalar::from(7u64);
+        }
+        let expected: Scalar = inputs.iter().product::<Scalar>().invert();
+
+        let mut array = inputs;
+        assert_eq!(Scalar::batch_invert_array(&mut array), expected);
+
+        let mut slice = inputs;
+        let mut scratch = [Scalar::ZERO; 20];
+        assert_eq!(
+            Scalar::batch_invert_with_scratch(&mut slice, &mut scratch),
+            expected
+        );
+
+        for ((a, b), x) in array.iter().zip(slice.iter()).zip(inputs.iter()) {
+            assert_eq!(a * x, Scalar::ONE);
+            assert_eq!(a, b);
+        }
+
+        assert_eq!(Scalar::batch_invert_array(&mut []), Scalar::ONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn batch_invert_with_short_scratch_panics() {
+        let mut inputs = [Scalar::ONE; 4];
+        Scalar::batch_invert_with_scratch(&mut inputs, &mut [Scalar::ZERO; 3]);
+    }
+
+    #[test]
+    fn sqrt_and_legendre() {
+        let mut rng = rand::thread_rng();
+
+        assert_eq!(Scalar::ZERO.sqrt().unwrap(), Scalar::ZERO);
+        assert_eq!(Scalar::ZERO.legendre(), 0);
+        // The even root of 1 is l - 1, since l is odd.
+        assert_eq!(Scalar::ONE.sqrt().unwrap(), -Scalar::ONE);
+        assert_eq!(Scalar::ONE.legendre(), 1);
+
+        // -1 is a square, since l = 1 (mod 4).
+        let minus_one = -Scalar::ONE;
+        let i = minus_one.sqrt().unwrap();
+        assert_eq!(i * i, minus_one);
+
+        for _ in 0..64 {
+            let x = Scalar::random(&mut rng);
+            let x_sq = x * x;
+
+            let root = x_sq.sqrt().unwrap();
+            assert!(root == x || root == -x);
+            assert_eq!(root.bytes[0] & 1, 0);
+            assert_eq!(x_sq.legendre(), 1);
+
+            // 2 is a nonsquare, so 2x^2 is too.
+            let nonsquare = x_sq + x_sq;
+            assert!(bool::from(nonsquare.sqrt().is_none()));
+            assert_eq!(nonsquare.legendre(), -1);
+        }
+    }
+
+    #[test]
+    fn pow_matches_repeated_multiplication() {
+        let mut rng = rand::thread_rng();
+        let x = Scalar::random(&mut rng);
+
+        let mut expected = Scalar::ONE;
+        for e in 0..40u64 {
+            assert_eq!(x.pow(&[e, 0, 0, 0]), expected);
+            assert_eq!(x.pow_vartime(&[e, 0, 0, 0]), expected);
+            expected *= x;
+        }
+
+        // x^(l-2) = 1/x
+        let l_minus_two = [
+            0x5812_631a_5cf5_d3eb,
+            0x14de_f9de_a2f7_9cd6,
+            0x0000_0000_0000_0000,
+            0x1000_0000_0000_0000,
+        ];
+        assert_eq!(x.pow(&l_minus_two), x.invert());
+        assert_eq!(x.pow_vartime(&l_minus_two), x.invert());
+
+        // The constant-time and variable-time versions agree on large exponents.
+        let e = [u64::MAX; 4];
+        assert_eq!(x.pow(&e), x.pow_vartime(&e));
+        assert_eq!(Scalar::ZERO.pow(&[0; 4]), Scalar::ONE);
+    }
+
+    #[test]
+    fn mul_add() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            let c = Scalar::random(&mut rng);
+            assert_eq!(a.mul_add(&b, &c), a * b + c);
+        }
+
+        // Extreme inputs exercise the extra subtraction.
+        let l_minus_one = -Scalar::ONE;
+        assert_eq!(
+            l_minus_one.mul_add(&l_minus_one, &l_minus_one),
+            Scalar::ZERO
+        );
+        assert_eq!(X.mul_add(&Y, &Scalar::ZERO), X * Y);
+        assert_eq!(Scalar::ZERO.mul_add(&Y, &X), X);
+    }
+
+    #[test]
+    fn random_nonzero_and_below() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            assert_ne!(Scalar::random_nonzero(&mut rng), Scalar::ZERO);
+        }
+
+        // Every value below a small bound is hit, and nothing else.
+        let bound = Scalar::from(5u64);
+        let mut seen = [false; 5];
+        for _ in 0..200 {
+            let x = Scalar::random_below(&bound, &mut rng);
+            assert!(bool::from(x.ct_lt(&bound)));
+            seen[x.bytes[0] as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        assert_eq!(Scalar::random_below(&Scalar::ONE, &mut rng), Scalar::ZERO);
+
+        // The largest bound still yields canonical scalars.
+        let l_minus_one = -Scalar::ONE;
+        for _ in 0..100 {
+            let x = Scalar::random_below(&l_minus_one, &mut rng);
+            assert!(bool::from(x.is_canonical()));
+            assert_ne!(x, l_minus_one);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "bound must be nonzero")]
+    fn random_below_zero_bound() {
+        Scalar::random_below(&Scalar::ZERO, &mut rand::thread_rng());
+    }
+
+    #[test]
+    fn from_canonical_bytes_const() {
+        const ONE: Scalar = Scalar::from_canonical_bytes_const(Scalar::ONE.bytes);
+        assert_eq!(ONE, Scalar::ONE);
+        assert_eq!(Scalar::from_canonical_bytes_const(X.bytes), X);
+
+        let l_minus_one = (-Scalar::ONE).bytes;
+        assert_eq!(
+            Scalar::from_canonical_bytes_const(l_minus_one),
+            -Scalar::ONE
+        );
+        assert_eq!(Scalar::from_canonical_bytes_const([0u8; 32]), Scalar::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "scalar bytes are not canonical")]
+    fn from_canonical_bytes_const_rejects_l() {
+        Scalar::from_canonical_bytes_const(constants::BASEPOINT_ORDER_PRIVATE.bytes);
+    }
+
+    #[test]
+    fn big_endian_bytes_roundtrip() {
+        let mut be = X.to_bytes();
+        be.reverse();
+        assert_eq!(X.to_bytes_be(), be);
+        assert_eq!(Scalar::from_canonical_bytes_be(be).unwrap(), X);
+        assert_eq!(Scalar::from_bytes_mod_order_be(be), X);
+
+        let mut l = constants::BASEPOINT_ORDER_PRIVATE.bytes;
+        l.reverse();
+        assert!(bool::from(Scalar::from_canonical_bytes_be(l).is_none()));
+    }
+
+    #[test]
+    fn from_u64_limbs_mod_order_wide() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let mut bytes = [0u8; 64];
+            rng.fill_bytes(&mut bytes);
+            let mut limbs = [0u64; 8];
+            read_le_u64_into(&bytes, &mut limbs);
+
+            assert_eq!(
+                Scalar::from_u64_limbs_mod_order_wide(&limbs),
+                Scalar::from_bytes_mod_order_wide(&bytes)
+            );
+        }
+        assert_eq!(
+            Scalar::from_u64_limbs_mod_order_wide(&[u64::MAX; 8]),
+            Scalar::from_bytes_mod_order_wide(&[0xff; 64])
+        );
+    }
+
+    #[test]
+    fn bits_le_and_be() {
+        assert_eq!(X.bits_le().count(), 256);
+        for (i, bit) in X.bits_le().enumerate() {
+            assert_eq!(bit, (X.bytes[i / 8] >> (i % 8)) & 1 == 1);
+        }
+
+        assert!(X.bits_be().eq(X.bits_le().rev()));
+        assert!(X.bits_be().take(3).all(|bit| !bit));
+    }
+
+    #[test]
+    fn is_canonical_bytes() {
+        let l = constants::BASEPOINT_ORDER_PRIVATE.bytes;
+        let mut l_minus_one = l;
+        l_minus_one[0] -= 1;
+        let mut high_bit = [0u8; 32];
+        high_bit[31] = 0x80;
+
+        assert!(bool::from(Scalar::is_canonical_bytes(&X.bytes)));
+        assert!(bool::from(Scalar::is_canonical_bytes(&l_minus_one)));
+        assert!(!bool::from(Scalar::is_canonical_bytes(&l)));
+        assert!(!bool::from(Scalar::is_canonical_bytes(&high_bit)));
+        assert!(!bool::from(Scalar::is_canonical_bytes(
+            &LARGEST_UNREDUCED_SCALAR.bytes
+        )));
+
+        assert!(bool::from(X.is_canonical()));
+        assert!(!bool::from(LARGEST_UNREDUCED_SCALAR.is_canonical()));
+    }
+
+    #[test]
+    fn halve_and_div_pow2() {
+        let mut rng = rand::thread_rng();
+        let x = Scalar::random(&mut rng);
+        let two = Scalar::from(2u64);
+
+        assert_eq!(INV_TWO * two, Scalar::ONE);
+        assert_eq!(x.halve() * two, x);
+        assert_eq!(Scalar::ONE.halve(), INV_TWO);
+
+        let mut expected = x;
+        for k in 0..70 {
+            assert_eq!(x.div_pow2(k), expected);
+            expected = expected.halve();
+        }
+
+        let k = u32::MAX;
+        let two_pow_k = two.pow_vartime(&[k as u64, 0, 0, 0]);
+        assert_eq!(x.div_pow2(k) * two_pow_k, x);
+    }
+
+    #[test]
+    fn constant_time_comparison() {
+        let mut rng = rand::thread_rng();
+
+        let small = Scalar::from(5u64);
+        let large = -Scalar::ONE;
+        assert!(bool::from(large.ct_gt(&small)));
+        assert!(bool::from(small.ct_lt(&large)));
+        assert!(!bool::from(small.ct_gt(&small)));
+        assert!(!bool::from(small.ct_lt(&small)));
+
+        // Differences in low bytes only matter when the high bytes agree.
+        assert!(bool::from(
+            Scalar::from(256u64).ct_gt(&Scalar::from(255u64))
+        ));
+
+        for _ in 0..100 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            let (a_int, b_int) = (a.bytes.iter().rev(), b.bytes.iter().rev());
+            let expected = a_int.cmp(b_int);
+            assert_eq!(
+                bool::from(a.ct_gt(&b)),
+                expected == core::cmp::Ordering::Greater
+            );
+            assert_eq!(
+                bool::from(a.ct_lt(&b)),
+                expected == core::cmp::Ordering::Less
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn from_bytes_mod_order_var_len() {
+        let mut rng = rand::thread_rng();
+
+        // The integers 0x00 0x01 ... 0xc7, in each byte order, reduced
+        // independently with Python's integers.
+        let input: Vec<u8> = (0..200u8).collect();
+        assert_eq!(
+            Scalar::from_bytes_mod_order_var_len(&input).as_bytes(),
+            &hex::decode("cb406aea511116c85c51f1a052367ad636636084de9b0d4489d3bc0c58ac3902")
+                .unwrap()[..],
+        );
+        assert_eq!(
+            Scalar::from_bytes_mod_order_var_len_be(&input).as_bytes(),
+            &hex::decode("d4d951cc7e89652380bbde449c4fb76ef9cf3d83f82adff9aefda6684673e906")
+                .unwrap()[..],
+        );
+
+        // Agreement with the fixed-length reductions.
+        let mut wide = [0u8; 64];
+        rng.fill_bytes(&mut wide);
+        assert_eq!(
+            Scalar::from_bytes_mod_order_var_len(&wide),
+            Scalar::from_bytes_mod_order_wide(&wide)
+        );
+        let mut narrow = [0u8; 32];
+        rng.fill_bytes(&mut narrow);
+        assert_eq!(
+            Scalar::from_bytes_mod_order_var_len(&narrow),
+            Scalar::from_bytes_mod_order(narrow)
+        );
+        let mut narrow_be = narrow;
+        narrow_be.reverse();
+        assert_eq!(
+            Scalar::from_bytes_mod_order_be(narrow_be),
+            Scalar::from_bytes_mod_order(narrow)
+        );
+        assert_eq!(
+            Scalar::from_bytes_mod_order_var_len_be(&narrow_be),
+            Scalar::from_bytes_mod_order(narrow)
+        );
+
+        assert_eq!(Scalar::from_bytes_mod_order_var_len(&[]), Scalar::ZERO);
+        assert_eq!(Scalar::from_bytes_mod_order_var_len_be(&[]), Scalar::ZERO);
+    }
+
+    #[test]
+    #[cfg(feature = "digest")]
+    fn hash_from_bytes_xof() {
+        use sha3::{Shake128, Shake256};
+
+        // Expected outputs computed independently with Python's hashlib.
+        assert_eq!(
+            Scalar::hash_from_bytes_xof::<Shake128>(b"abc").as_bytes(),
+            &hex::decode("39d7e7c2dd28ade191eb02f35040062157f26dc20d8b254b9ce47f8029945c08")
+                .unwrap()[..],
+        );
+        assert_eq!(
+            Scalar::hash_from_bytes_xof::<Shake256>(b"abc").as_bytes(),
+            &hex::decode("58a7c929788ce46be55c2fb0698a6ca6d9ba7073f6e1a124150859e287a1d008")
+                .unwrap()[..],
+        );
+
+        // Successive reads consume successive output blocks.
+        let mut xof = Shake256::default();
+        Update::update(&mut xof, b"abc");
+        let mut reader = xof.finalize_xof();
+        let mut stream = [0u8; 128];
+        reader.read(&mut stream);
+
+        let mut xof = Shake256::default();
+        Update::update(&mut xof, b"abc");
+        let mut reader = xof.finalize_xof();
+        assert_eq!(
+            Scalar::from_xof(&mut reader),
+            Scalar::from_bytes_mod_order_wide(stream[..64].try_into().unwrap())
+        );
+        assert_eq!(
+            Scalar::from_xof(&mut reader),
+            Scalar::from_bytes_mod_order_wide(stream[64..].try_into().unwrap())
+        );
+    }
+
     #[cfg(feature = "precomputed-tables")]
     fn test_pippenger_radix_iter(scalar: Scalar, w: usize) {
         let digits_count = Scalar::to_radix_2w_size_hint(w);
@@ -1896,6 +3409,27 @@ pub(crate) mod test {
         }
     }
 
+    #[test]
+    fn as_radix_2w_reconstructs_scalar() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let x = Scalar::random(&mut rng);
+            assert_eq!(x.as_radix_16(), x.as_radix_2w(4));
+
+            for w in 4..=8 {
+                let digits = x.as_radix_2w(w);
+                let radix = Scalar::from(1u64 << w);
+                let half = 1i16 << (w - 1);
+                let y = digits.iter().rev().fold(Scalar::ZERO, |acc, &d| {
+                    assert!((-half..=half).contains(&i16::from(d)));
+                    let abs = Scalar::from(d.unsigned_abs() as u64);
+                    acc * radix + if d < 0 { -abs } else { abs }
+                });
+                assert_eq!(x, y);
+            }
+        }
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn test_read_le_u64_into() {
@@ -2000,6 +3534,59 @@ pub(crate) mod test {
         assert!(bool::from(Scalar::from_repr([0xff; 32]).is_none()));
     }
 
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_traits_impls() {
+        use num_traits::{CheckedAdd, CheckedMul, CheckedNeg, CheckedSub, Inv, One, Zero};
+
+        assert!(Zero::is_zero(&<Scalar as Zero>::zero()));
+        assert!(!Zero::is_zero(&X));
+        assert!(<Scalar as One>::one().is_one());
+        assert!(!X.is_one());
+
+        assert_eq!(X.inv(), XINV);
+        assert_eq!((&XINV).inv(), X);
+        assert_eq!(Scalar::ZERO.inv(), Scalar::ZERO);
+
+        assert_eq!(X.checked_add(&Y), Some(X + Y));
+        assert_eq!(X.checked_sub(&Y), Some(X - Y));
+        assert_eq!(X.checked_mul(&Y), Some(X * Y));
+        assert_eq!(X.checked_neg(), Some(-X));
+    }
+
+    #[cfg(feature = "group-bits")]
+    #[test]
+    fn ff_bits() {
+        let bits = X.to_le_bits();
+        assert_eq!(bits.len(), 256);
+        for (i, bit) in bits.iter().enumerate() {
+            assert_eq!(*bit, (X.bytes[i / 8] >> (i % 8)) & 1 == 1);
+        }
+
+        // The characteristic is l = 2^252 + 27742317777372353535851937790883648493.
+        let char_bits = Scalar::char_le_bits();
+        assert_eq!(char_bits.iter().rposition(|b| *b), Some(252));
+        assert!(char_bits[0] && !char_bits[1] && char_bits[2]);
+    }
+
+    #[cfg(feature = "group")]
+    #[test]
+    fn ff_from_uniform_bytes() {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(X.as_bytes());
+        assert_eq!(Scalar::from_uniform_bytes(&bytes), X);
+
+        // (2^512 - 1) mod l
+        let expected = Scalar {
+            bytes: [
+                0x00, 0x0f, 0x9c, 0x44, 0xe3, 0x11, 0x06, 0xa4, 0x47, 0x93, 0x85, 0x68, 0xa7, 0x1b,
+                0x0e, 0xd0, 0x65, 0xbe, 0xf5, 0x17, 0xd2, 0x73, 0xec, 0xce, 0x3d, 0x9a, 0x30, 0x7c,
+                0x1b, 0x41, 0x99, 0x03,
+            ],
+        };
+        assert_eq!(Scalar::from_uniform_bytes(&[0xff; 64]), expected);
+    }
+
     #[test]
     #[should_panic]
     fn test_read_le_u64_into_should_panic_on_bad_input() {
@@ -2076,4 +3663,112 @@ pub(crate) mod test {
             assert_eq!(a * c.reduce(), reduced_mul_ac);
         }
     }
+    /// An RNG for the `rand_core` 0.9 traits which returns the bytes
+    /// 0, 1, 2, ... in turn.
+    #[cfg(feature = "rand_core_0_9")]
+    struct CountingRng(u8);
+
+    #[cfg(feature = "rand_core_0_9")]
+    impl rand_core_0_9::RngCore for CountingRng {
+        fn next_u32(&mut self) -> u32 {
+            let mut bytes = [0u8; 4];
+            self.fill_bytes(&mut bytes);
+            u32::from_le_bytes(bytes)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let mut bytes = [0u8; 8];
+            self.fill_bytes(&mut bytes);
+            u64::from_le_bytes(bytes)
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for b in dst {
+                *b = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+    }
+
+    #[cfg(feature = "rand_core_0_9")]
+    impl rand_core_0_9::CryptoRng for CountingRng {}
+
+    /// An RNG for the `rand_core` 0.9 traits which always fails.
+    #[cfg(feature = "rand_core_0_9")]
+    struct FailingRng;
+
+    #[cfg(feature = "rand_core_0_9")]
+    impl rand_core_0_9::TryRngCore for FailingRng {
+        type Error = core::fmt::Error;
+
+        fn try_next_u32(&mut self) -> Result<u32, core::fmt::Error> {
+            Err(core::fmt::Error)
+        }
+
+        fn try_next_u64(&mut self) -> Result<u64, core::fmt::Error> {
+            Err(core::fmt::Error)
+        }
+
+        fn try_fill_bytes(&mut self, _dst: &mut [u8]) -> Result<(), core::fmt::Error> {
+            Err(core::fmt::Error)
+        }
+    }
+
+    #[cfg(feature = "rand_core_0_9")]
+    impl rand_core_0_9::TryCryptoRng for FailingRng {}
+
+    #[test]
+    #[cfg(feature = "rand_core_0_9")]
+    fn random_0_9() {
+        let bytes: [u8; 64] = core::array::from_fn(|i| i as u8);
+        let expected = Scalar::from_bytes_mod_order_wide(&bytes);
+
+        assert_eq!(Scalar::random_0_9(&mut CountingRng(0)), expected);
+        assert_eq!(Scalar::try_random_0_9(&mut CountingRng(0)), Ok(expected));
+        assert_eq!(
+            Scalar::try_random_0_9(&mut FailingRng),
+            Err(core::fmt::Error)
+        );
+
+        // RistrettoPoint::random_0_9 consumes the same 64 bytes.
+        use crate::ristretto::RistrettoPoint;
+        let expected = RistrettoPoint::from_uniform_bytes(&bytes);
+        assert_eq!(RistrettoPoint::random_0_9(&mut CountingRng(0)), expected);
+        assert_eq!(
+            RistrettoPoint::try_random_0_9(&mut CountingRng(0)),
+            Ok(expected)
+        );
+        assert!(RistrettoPoint::try_random_0_9(&mut FailingRng).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "crypto-bigint")]
+    fn crypto_bigint_conversions() {
+        use crypto_bigint::{Encoding, U256, U512};
+
+        let x = Scalar::from(0x0102_0304_0506_0708u64) * Scalar::from(u64::MAX);
+        let x_u256 = U256::from(x);
+        assert_eq!(x_u256.to_le_bytes(), x.to_bytes());
+        assert_eq!(
+            Scalar::from_canonical_u256(&x_u256).expect("x is canonical"),
+            x
+        );
+        assert_eq!(Scalar::from_u256_mod_order(&x_u256), x);
+        assert_eq!(Scalar::from_u512_mod_order(&U512::from(x)), x);
+
+        // ℓ is not canonical, and reduces to zero.
+        let l = U256::from_le_bytes(constants::BASEPOINT_ORDER_PRIVATE.to_bytes());
+        assert!(bool::from(Scalar::from_canonical_u256(&l).is_none()));
+        assert_eq!(Scalar::from_u256_mod_order(&l), Scalar::ZERO);
+
+        // 2^512 - 1 reduces as the wide byte reduction does.
+        assert_eq!(
+            Scalar::from_u512_mod_order(&U512::MAX),
+            Scalar::from_bytes_mod_order_wide(&[0xff; 64])
+        );
+        assert_eq!(
+            Scalar::from_u256_mod_order(&U256::MAX),
+            Scalar::from_bytes_mod_order([0xff; 32])
+        );
+    }
 }
@@ -0,0 +1,192 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Lagrange interpolation over the scalar field.
+//!
+//! Given a set of distinct participant indices \\(x\_0, \ldots, x\_{n-1}\\),
+//! the Lagrange coefficients at a point \\(x\\) are
+//! $$
+//! \lambda\_i(x) = \prod\_{j \ne i} \frac{x - x\_j}{x\_i - x\_j},
+//! $$
+//! so that any polynomial \\(f\\) of degree less than \\(n\\) satisfies
+//! \\(f(x) = \sum\_i \lambda\_i(x) f(x\_i)\\).  Threshold schemes such as
+//! FROST use the coefficients at zero to combine shares of a secret, or
+//! the corresponding signature shares, without reconstructing it.
+//!
+//! All denominators are inverted together with
+//! [`Scalar::batch_invert`], so computing a full set of coefficients costs
+//! a single scalar inversion.
+//!
+//! The participant indices and the evaluation point are treated as
+//! public: the functions in this module may run in variable time with
+//! respect to them.  The share values passed to [`interpolate_at_zero`]
+//! are only used in constant-time operations.
+//!
+//! # Example
+//!
+//! ```
+//! # fn main() {
+//! use curve25519_dalek::scalar::{lagrange, Scalar};
+//!
+//! // Shares of f(x) = 7 + 5x at x = 1, 2.
+//! let indices = [Scalar::from(1u64), Scalar::from(2u64)];
+//! let shares = [Scalar::from(12u64), Scalar::from(17u64)];
+//!
+//! let secret = lagrange::interpolate_at_zero(&indices, &shares).expect("indices are distinct");
+//! assert_eq!(secret, Scalar::from(7u64));
+//! # }
+//! ```
+
+use alloc::vec::Vec;
+
+use super::Scalar;
+
+/// Compute the Lagrange coefficients \\(\lambda\_i(x)\\) for the given
+/// participant `indices`, evaluated at `x`.
+///
+/// The returned coefficients are in the same order as `indices`.
+///
+/// # Returns
+///
+/// `None` if `indices` contains a repeated entry, in which case the
+/// coefficients are undefined.
+pub fn coefficients(indices: &[Scalar], x: &Scalar) -> Option<Vec<Scalar>> {
+    let mut denominators = denominators(indices)?;
+
+    // The numerator of the i-th coefficient is the product of all the
+    // (x - x_j) except the i-th, which we compute from prefix and suffix
+    // products to avoid dividing by a possibly-zero factor.
+    let mut numerators = Vec::with_capacity(indices.len());
+    let mut prefix = Scalar::ONE;
+    for x_j in indices {
+        numerators.push(prefix);
+        prefix *= x - x_j;
+    }
+    let mut suffix = Scalar::ONE;
+    for (numerator, x_j) in numerators.iter_mut().zip(indices).rev() {
+        *numerator *= suffix;
+        suffix *= x - x_j;
+    }
+
+    Scalar::batch_invert(&mut denominators);
+
+    Some(
+        numerators
+            .iter()
+            .zip(&denominators)
+            .map(|(numerator, inv_denominator)| numerator * inv_denominator)
+            .collect(),
+    )
+}
+
+/// Compute the Lagrange coefficients \\(\lambda\_i(0)\\) for the given
+/// participant `indices`.
+///
+/// This is the common case of [`coefficients`], used to recover a shared
+/// secret \\(f(0)\\) from its shares.
+///
+/// # Returns
+///
+/// `None` if `indices` contains a repeated entry.
+pub fn coefficients_at_zero(indices: &[Scalar]) -> Option<Vec<Scalar>> {
+    coefficients(indices, &Scalar::ZERO)
+}
+
+/// Interpolate the polynomial through the points \\((x\_i, y\_i)\\) given by
+/// `indices` and `values`, and evaluate it at zero.
+///
+/// # Returns
+///
+/// `None` if `indices` contains a repeated entry.
+///
+/// # Panics
+///
+/// Panics if `indices` and `values` have different lengths.
+pub fn interpolate_at_zero(indices: &[Scalar], values: &[Scalar]) -> Option<Scalar> {
+    assert_eq!(indices.len(), values.len());
+
+    let lambdas = coefficients_at_zero(indices)?;
+    Some(
+        lambdas
+            .iter()
+            .zip(values)
+            .map(|(lambda, y)| lambda * y)
+            .sum(),
+    )
+}
+
+/// Compute the denominators \\(\prod\_{j \ne i} (x\_i - x\_j)\\), or `None` if
+/// any of them vanishes.
+fn denominators(indices: &[Scalar]) -> Option<Vec<Scalar>> {
+    let mut denominators = Vec::with_capacity(indices.len());
+    for (i, x_i) in indices.iter().enumerate() {
+        let mut denominator = Scalar::ONE;
+        for (j, x_j) in indices.iter().enumerate() {
+            if i != j {
+                denominator *= x_i - x_j;
+            }
+        }
+        if denominator == Scalar::ZERO {
+            return None;
+        }
+        denominators.push(denominator);
+    }
+    Some(denominators)
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Evaluate the polynomial with the given coefficients at `x`.
+    fn evaluate(poly: &[Scalar], x: &Scalar) -> Scalar {
+        poly.iter()
+            .rev()
+            .fold(Scalar::ZERO, |acc, a_j| acc * x + a_j)
+    }
+
+    #[test]
+    fn interpolation_recovers_polynomial() {
+        let mut rng = rand::thread_rng();
+        let poly: Vec<Scalar> = (0..5).map(|_| Scalar::random(&mut rng)).collect();
+        let indices: Vec<Scalar> = [3u64, 1, 4, 15, 9]
+            .iter()
+            .map(|&i| Scalar::from(i))
+            .collect();
+        let values: Vec<Scalar> = indices.iter().map(|x| evaluate(&poly, x)).collect();
+
+        assert_eq!(interpolate_at_zero(&indices, &values), Some(poly[0]));
+
+        for x in [Scalar::from(2u64), indices[2], Scalar::random(&mut rng)] {
+            let lambdas = coefficients(&indices, &x).expect("indices are distinct");
+            let y: Scalar = lambdas.iter().zip(&values).map(|(l, y)| l * y).sum();
+            assert_eq!(y, evaluate(&poly, &x));
+        }
+    }
+
+    #[test]
+    fn coefficients_at_index_are_unit_vectors() {
+        let indices = [Scalar::from(1u64), Scalar::from(2u64), Scalar::from(5u64)];
+        let lambdas = coefficients(&indices, &indices[1]).expect("indices are distinct");
+        assert_eq!(lambdas, [Scalar::ZERO, Scalar::ONE, Scalar::ZERO]);
+    }
+
+    #[test]
+    fn repeated_indices_are_rejected() {
+        let indices = [Scalar::from(1u64), Scalar::from(2u64), Scalar::from(1u64)];
+        assert!(coefficients_at_zero(&indices).is_none());
+        assert!(interpolate_at_zero(&indices, &[Scalar::ONE; 3]).is_none());
+    }
+
+    #[test]
+    fn empty_indices() {
+        assert_eq!(coefficients_at_zero(&[]), Some(Vec::new()));
+        assert_eq!(interpolate_at_zero(&[], &[]), Some(Scalar::ZERO));
+    }
+}
@@ -0,0 +1,194 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! A misuse-resistant handle for long-term secret scalars.
+
+use core::ops::Mul;
+
+use subtle::{Choice, ConstantTimeEq, CtOption};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "rand_core")]
+use rand_core::CryptoRngCore;
+
+use super::Scalar;
+use crate::edwards::EdwardsPoint;
+use crate::montgomery::MontgomeryPoint;
+use crate::ristretto::RistrettoPoint;
+
+/// A secret `Scalar`, such as a long-term private key.
+///
+/// Unlike `Scalar`, a `SecretScalar` is not `Copy` and does not implement
+/// `Debug`, so it cannot be duplicated or logged by accident, and it is
+/// overwritten with zeroes when it falls out of scope.  It supports only
+/// the operations needed to use a key: multiplying points and computing
+/// the matching public point.  Anything else must go through
+/// [`SecretScalar::expose_secret`], which makes such uses easy to audit.
+///
+/// # Example
+///
+#[cfg_attr(feature = "rand_core", doc = "```")]
+#[cfg_attr(not(feature = "rand_core"), doc = "```ignore")]
+/// # fn main() {
+/// use curve25519_dalek::ristretto::RistrettoPoint;
+/// use curve25519_dalek::scalar::SecretScalar;
+/// use rand_core::OsRng;
+///
+/// let alice = SecretScalar::random(&mut OsRng);
+/// let bob = SecretScalar::random(&mut OsRng);
+///
+/// let shared_a = &alice * &bob.public_ristretto();
+/// let shared_b = &bob * &alice.public_ristretto();
+/// assert_eq!(shared_a, shared_b);
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SecretScalar(Scalar);
+
+impl SecretScalar {
+    /// Wrap `scalar` as a secret.
+    pub fn new(scalar: Scalar) -> SecretScalar {
+        SecretScalar(scalar)
+    }
+
+    /// Return a secret `Scalar` chosen uniformly at random using a
+    /// user-provided RNG.
+    #[cfg(feature = "rand_core")]
+    pub fn random<R: CryptoRngCore + ?Sized>(rng: &mut R) -> SecretScalar {
+        SecretScalar(Scalar::random(rng))
+    }
+
+    /// Construct a secret from 64 bytes, such as the output of a hash or
+    /// a key derivation function, by reducing them modulo \\( \ell \\).
+    pub fn from_bytes_mod_order_wide(bytes: &[u8; 64]) -> SecretScalar {
+        SecretScalar(Scalar::from_bytes_mod_order_wide(bytes))
+    }
+
+    /// Attempt to construct a secret from its canonical byte encoding.
+    ///
+    /// Returns `None` if `bytes` is not a canonical encoding.
+    pub fn from_canonical_bytes(bytes: [u8; 32]) -> CtOption<SecretScalar> {
+        Scalar::from_canonical_bytes(bytes).map(SecretScalar)
+    }
+
+    /// Compute the public point \\( xB \\) on the Edwards curve, where
+    /// \\( B \\) is the Ed25519 basepoint.
+    pub fn public_edwards(&self) -> EdwardsPoint {
+        EdwardsPoint::mul_base(&self.0)
+    }
+
+    /// Compute the public point \\( xB \\) in the Ristretto group, where
+    /// \\( B \\) is the Ristretto basepoint.
+    pub fn public_ristretto(&self) -> RistrettoPoint {
+        RistrettoPoint::mul_base(&self.0)
+    }
+
+    /// Borrow the underlying `Scalar`.
+    ///
+    /// The returned value is not protected: copies made from it are
+    /// neither zeroized nor hidden from `Debug`.
+    pub fn expose_secret(&self) -> &Scalar {
+        &self.0
+    }
+}
+
+impl From<Scalar> for SecretScalar {
+    fn from(scalar: Scalar) -> SecretScalar {
+        SecretScalar(scalar)
+    }
+}
+
+impl ConstantTimeEq for SecretScalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<'a, 'b> Mul<&'b EdwardsPoint> for &'a SecretScalar {
+    type Output = EdwardsPoint;
+    fn mul(self, point: &'b EdwardsPoint) -> EdwardsPoint {
+        point.mul(&self.0)
+    }
+}
+
+impl<'a, 'b> Mul<&'b RistrettoPoint> for &'a SecretScalar {
+    type Output = RistrettoPoint;
+    fn mul(self, point: &'b RistrettoPoint) -> RistrettoPoint {
+        point.mul(&self.0)
+    }
+}
+
+impl<'a, 'b> Mul<&'b MontgomeryPoint> for &'a SecretScalar {
+    type Output = MontgomeryPoint;
+    fn mul(self, point: &'b MontgomeryPoint) -> MontgomeryPoint {
+        point.mul(&self.0)
+    }
+}
+
+impl Zeroize for SecretScalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for SecretScalar {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretScalar {}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod test {
+    use super::*;
+    use crate::constants;
+
+    #[test]
+    fn key_operations_match_scalar() {
+        let x = Scalar::random(&mut rand::thread_rng());
+        let secret = SecretScalar::from(x);
+
+        assert_eq!(secret.public_edwards(), EdwardsPoint::mul_base(&x));
+        assert_eq!(secret.public_ristretto(), RistrettoPoint::mul_base(&x));
+
+        let P = constants::RISTRETTO_BASEPOINT_POINT * Scalar::from(7u64);
+        assert_eq!(&secret * &P, P * x);
+        let Q = constants::ED25519_BASEPOINT_POINT * Scalar::from(7u64);
+        assert_eq!(&secret * &Q, Q * x);
+        assert_eq!(&secret * &Q.to_montgomery(), (Q * x).to_montgomery());
+
+        assert!(bool::from(secret.ct_eq(&SecretScalar::new(x))));
+        assert_eq!(secret.expose_secret(), &x);
+    }
+
+    #[test]
+    fn constructors() {
+        let x = Scalar::random(&mut rand::thread_rng());
+        let secret = SecretScalar::from_canonical_bytes(x.to_bytes()).unwrap();
+        assert_eq!(secret.expose_secret(), &x);
+        assert!(bool::from(
+            SecretScalar::from_canonical_bytes([0xff; 32]).is_none()
+        ));
+
+        let wide = [0x42; 64];
+        assert_eq!(
+            SecretScalar::from_bytes_mod_order_wide(&wide).expose_secret(),
+            &Scalar::from_bytes_mod_order_wide(&wide)
+        );
+    }
+
+    #[test]
+    fn zeroize() {
+        let mut secret = SecretScalar::new(Scalar::ONE);
+        secret.zeroize();
+        assert_eq!(secret.expose_secret(), &Scalar::ZERO);
+    }
+}
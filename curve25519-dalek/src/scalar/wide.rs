@@ -0,0 +1,209 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Unreduced products of scalars, for deferring reductions.
+
+use core::borrow::Borrow;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign};
+
+use subtle::{Choice, ConstantTimeEq};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use super::{read_le_u64_into, Scalar};
+
+/// An unreduced integer of up to 576 bits, used to accumulate products of
+/// `Scalar`s and reduce only once at the end.
+///
+/// A product of two scalars is less than \\( 2\^{506} \\), so more than
+/// \\( 2\^{64} \\) products can be summed before the accumulator could
+/// overflow, which is more than any computation can reach.
+///
+/// All operations run in constant time.
+///
+/// # Example
+///
+/// ```
+/// # use curve25519_dalek::scalar::{Scalar, WideScalar};
+/// let a = [Scalar::from(2u64), Scalar::from(3u64), -Scalar::ONE];
+/// let b = [Scalar::from(5u64), Scalar::from(7u64), Scalar::from(11u64)];
+///
+/// // Compute the inner product with a single reduction.
+/// let inner_product = a
+///     .iter()
+///     .zip(&b)
+///     .map(|(a, b)| WideScalar::mul_wide(a, b))
+///     .sum::<WideScalar>()
+///     .reduce();
+/// assert_eq!(inner_product, Scalar::from(20u64));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct WideScalar {
+    /// The value as little-endian 64-bit limbs.
+    limbs: [u64; 9],
+}
+
+impl WideScalar {
+    /// The value zero.
+    pub const ZERO: Self = Self { limbs: [0; 9] };
+
+    /// Compute the full 512-bit product of `a` and `b`, without reducing
+    /// it modulo \\( \ell \\).
+    pub fn mul_wide(a: &Scalar, b: &Scalar) -> WideScalar {
+        let a = u64_limbs(a);
+        let b = u64_limbs(b);
+
+        let mut limbs = [0u64; 9];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let t = (a[i] as u128) * (b[j] as u128) + (limbs[i + j] as u128) + carry;
+                limbs[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            limbs[i + 4] = carry as u64;
+        }
+
+        WideScalar { limbs }
+    }
+
+    /// Reduce this value modulo \\( \ell \\).
+    pub fn reduce(&self) -> Scalar {
+        let mut bytes = [0u8; 72];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(self.limbs.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        Scalar::from_bytes_mod_order_var_len(&bytes)
+    }
+}
+
+/// Read the scalar as little-endian 64-bit limbs.
+fn u64_limbs(s: &Scalar) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    read_le_u64_into(&s.bytes, &mut limbs);
+    limbs
+}
+
+impl From<Scalar> for WideScalar {
+    fn from(s: Scalar) -> WideScalar {
+        let mut limbs = [0u64; 9];
+        limbs[..4].copy_from_slice(&u64_limbs(&s));
+        WideScalar { limbs }
+    }
+}
+
+/// Equality of `WideScalar`s is defined mod \\( \ell \\), so
+/// accumulators holding the same residue are equal.
+impl ConstantTimeEq for WideScalar {
+    fn ct_eq(&self, other: &WideScalar) -> Choice {
+        self.reduce().ct_eq(&other.reduce())
+    }
+}
+
+impl PartialEq for WideScalar {
+    fn eq(&self, other: &WideScalar) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for WideScalar {}
+
+impl<'a, 'b> Add<&'b WideScalar> for &'a WideScalar {
+    type Output = WideScalar;
+
+    fn add(self, rhs: &'b WideScalar) -> WideScalar {
+        let mut limbs = [0u64; 9];
+        let mut carry = 0u128;
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let t = (self.limbs[i] as u128) + (rhs.limbs[i] as u128) + carry;
+            *limb = t as u64;
+            carry = t >> 64;
+        }
+        WideScalar { limbs }
+    }
+}
+
+define_add_variants!(LHS = WideScalar, RHS = WideScalar, Output = WideScalar);
+
+impl<'b> AddAssign<&'b WideScalar> for WideScalar {
+    fn add_assign(&mut self, rhs: &'b WideScalar) {
+        *self = *self + rhs;
+    }
+}
+
+define_add_assign_variants!(LHS = WideScalar, RHS = WideScalar);
+
+impl<T> Sum<T> for WideScalar
+where
+    T: Borrow<WideScalar>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(WideScalar::ZERO, |acc, item| acc + item.borrow())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for WideScalar {
+    fn zeroize(&mut self) {
+        self.limbs.zeroize();
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn inner_product_matches_reduced_arithmetic() {
+        let mut rng = rand::thread_rng();
+        let mut acc = WideScalar::ZERO;
+        let mut expected = Scalar::ZERO;
+        for _ in 0..64 {
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            acc += WideScalar::mul_wide(&a, &b);
+            expected += a * b;
+        }
+        assert_eq!(acc.reduce(), expected);
+
+        let c = Scalar::random(&mut rng);
+        assert_eq!((acc + WideScalar::from(c)).reduce(), expected + c);
+
+        let products = [acc, WideScalar::from(c)];
+        assert_eq!(products.iter().sum::<WideScalar>().reduce(), expected + c);
+    }
+
+    #[test]
+    fn accumulator_exceeds_512_bits() {
+        // (l - 1)^2 repeated 2^8 times overflows 512 bits.
+        let l_minus_one = -Scalar::ONE;
+        let product = WideScalar::mul_wide(&l_minus_one, &l_minus_one);
+        let mut acc = WideScalar::ZERO;
+        for _ in 0..256 {
+            acc += product;
+        }
+        assert_ne!(acc.limbs[8], 0);
+        assert_eq!(acc.reduce(), Scalar::from(256u64));
+    }
+
+    #[test]
+    fn equality_is_mod_l() {
+        // (l - 1)^2 is a 505-bit integer, but is 1 mod l.
+        let l_minus_one = -Scalar::ONE;
+        let square = WideScalar::mul_wide(&l_minus_one, &l_minus_one);
+        assert_eq!(square, WideScalar::from(Scalar::ONE));
+        assert_ne!(square, WideScalar::from(l_minus_one));
+        assert_eq!(square + WideScalar::from(l_minus_one), WideScalar::ZERO);
+    }
+}
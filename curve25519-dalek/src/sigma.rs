@@ -0,0 +1,301 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+#![allow(non_snake_case)]
+
+//! Schnorr proofs of knowledge of a discrete logarithm in the Ristretto
+//! group.
+//!
+//! **This is a low-level building block.**  It proves knowledge of \\(x\\)
+//! such that \\(X = xB\\), where \\(B\\) is the Ristretto basepoint, and
+//! nothing more.  Protocols using it are responsible for binding the proof
+//! to their session through the `context` argument.
+//!
+//! The interactive protocol has three moves:
+//!
+//! 1. the prover [`commit`]s to a random nonce \\(r\\) by sending
+//!    \\(R = rB\\);
+//! 2. the verifier replies with a random [`challenge`] \\(c\\);
+//! 3. the prover sends the [`respond`]se \\(s = r + cx\\), and the verifier
+//!    checks that \\(sB = R + cX\\).
+//!
+//! [`DlogProof`] makes this non-interactive with the Fiat–Shamir transform,
+//! deriving the challenge from a hash function `D` of the caller's choice.
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "rand_core", doc = "```")]
+#![cfg_attr(not(feature = "rand_core"), doc = "```ignore")]
+//! # fn main() {
+//! use curve25519_dalek::ristretto::RistrettoPoint;
+//! use curve25519_dalek::scalar::Scalar;
+//! use curve25519_dalek::sigma::DlogProof;
+//! use rand_core::OsRng;
+//! use sha2::Sha512;
+//!
+//! let x = Scalar::random(&mut OsRng);
+//! let X = RistrettoPoint::mul_base(&x);
+//!
+//! let proof = DlogProof::prove::<Sha512, _>(&mut OsRng, b"my protocol", &x, &X);
+//! assert!(proof.verify::<Sha512>(b"my protocol", &X));
+//! assert!(!proof.verify::<Sha512>(b"another protocol", &X));
+//! # }
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use digest::generic_array::typenum::U64;
+use digest::Digest;
+
+#[cfg(feature = "rand_core")]
+use rand_core::CryptoRngCore;
+
+use crate::ristretto::{CompressedRistretto, RistrettoPoint};
+use crate::scalar::Scalar;
+#[cfg(feature = "alloc")]
+use crate::traits::{IsIdentity, VartimeMultiscalarMul};
+
+/// Domain separator prepended to every challenge hash input.
+const CHALLENGE_DOMAIN: &[u8] = b"curve25519-dalek Schnorr dlog proof";
+
+/// The first move of the protocol: pick a random nonce \\(r\\) and compute
+/// the commitment \\(R = rB\\).
+///
+/// The nonce must be kept secret, used for exactly one response, and then
+/// discarded; reusing it for two different challenges reveals the secret.
+#[cfg(feature = "rand_core")]
+pub fn commit<R: CryptoRngCore + ?Sized>(rng: &mut R) -> (Scalar, RistrettoPoint) {
+    let r = Scalar::random(rng);
+    (r, RistrettoPoint::mul_base(&r))
+}
+
+/// Derive the Fiat–Shamir challenge for the statement `X` and the
+/// commitment `R`, bound to the given `context`.
+///
+/// The hash input is the fixed domain separator, the length of `context`
+/// as a little-endian `u64`, `context` itself, and the encodings of `X` and
+/// `R`.
+pub fn challenge<D>(context: &[u8], X: &CompressedRistretto, R: &CompressedRistretto) -> Scalar
+where
+    D: Digest<OutputSize = U64> + Default,
+{
+    let hash = D::new()
+        .chain_update(CHALLENGE_DOMAIN)
+        .chain_update((context.len() as u64).to_le_bytes())
+        .chain_update(context)
+        .chain_update(X.as_bytes())
+        .chain_update(R.as_bytes());
+    Scalar::from_hash(hash)
+}
+
+/// The final move of the protocol: compute the response \\(s = r + cx\\)
+/// from the nonce `r`, the challenge `c` and the secret `x`.
+pub fn respond(r: &Scalar, c: &Scalar, x: &Scalar) -> Scalar {
+    r + c * x
+}
+
+/// A non-interactive proof of knowledge of the discrete logarithm of a
+/// `RistrettoPoint` with respect to the basepoint.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DlogProof {
+    /// The prover's commitment \\(R = rB\\).
+    pub R: CompressedRistretto,
+    /// The prover's response \\(s = r + cx\\).
+    pub s: Scalar,
+}
+
+impl DlogProof {
+    /// Prove knowledge of `x` such that `X = x * B`, bound to `context`.
+    ///
+    /// The caller must ensure that `X` is the public point for `x`; a proof
+    /// made for a mismatched pair will not verify.
+    #[cfg(feature = "rand_core")]
+    pub fn prove<D, R>(rng: &mut R, context: &[u8], x: &Scalar, X: &RistrettoPoint) -> DlogProof
+    where
+        D: Digest<OutputSize = U64> + Default,
+        R: CryptoRngCore + ?Sized,
+    {
+        let (r, R) = commit(rng);
+        let R = R.compress();
+        let c = challenge::<D>(context, &X.compress(), &R);
+        DlogProof {
+            R,
+            s: respond(&r, &c, x),
+        }
+    }
+
+    /// Verify this proof for the statement `X`, bound to `context`.
+    ///
+    /// This function runs in variable time.
+    pub fn verify<D>(&self, context: &[u8], X: &RistrettoPoint) -> bool
+    where
+        D: Digest<OutputSize = U64> + Default,
+    {
+        let c = challenge::<D>(context, &X.compress(), &self.R);
+        // Check that sB - cX = R by recomputing R from the response.
+        let R = RistrettoPoint::vartime_double_scalar_mul_basepoint(&-c, X, &self.s);
+        R.compress() == self.R
+    }
+
+    /// Verify a batch of `proofs` for the statements `Xs`, all bound to
+    /// the same `context`.
+    ///
+    /// The individual equations are combined with random weights drawn from
+    /// `rng` into a single multiscalar multiplication, which is
+    /// substantially faster than verifying each proof separately.  Returns
+    /// `true` only if every proof is valid, except with negligible
+    /// probability.
+    ///
+    /// This function runs in variable time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `proofs` and `Xs` have different lengths.
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    pub fn batch_verify<D, R>(
+        rng: &mut R,
+        context: &[u8],
+        proofs: &[DlogProof],
+        Xs: &[RistrettoPoint],
+    ) -> bool
+    where
+        D: Digest<OutputSize = U64> + Default,
+        R: CryptoRngCore + ?Sized,
+    {
+        assert_eq!(proofs.len(), Xs.len());
+
+        let Rs: Option<Vec<RistrettoPoint>> = proofs.iter().map(|p| p.R.decompress()).collect();
+        let Rs = match Rs {
+            Some(Rs) => Rs,
+            None => return false,
+        };
+
+        // Check sum z_i (s_i B - c_i X_i - R_i) = 0 for random weights z_i.
+        let zs: Vec<Scalar> = proofs.iter().map(|_| Scalar::random(rng)).collect();
+        let B_coefficient: Scalar = zs.iter().zip(proofs).map(|(z, p)| z * p.s).sum();
+        let X_coefficients = zs
+            .iter()
+            .zip(proofs)
+            .zip(Xs)
+            .map(|((z, p), X)| -(z * challenge::<D>(context, &X.compress(), &p.R)));
+        let R_coefficients = zs.iter().map(|z| -z);
+
+        RistrettoPoint::vartime_multiscalar_mul(
+            core::iter::once(B_coefficient)
+                .chain(X_coefficients)
+                .chain(R_coefficients),
+            core::iter::once(&crate::constants::RISTRETTO_BASEPOINT_POINT)
+                .chain(Xs)
+                .chain(&Rs),
+        )
+        .is_identity()
+    }
+
+    /// Serialize this proof as the 32-byte commitment followed by the
+    /// 32-byte response.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(self.R.as_bytes());
+        bytes[32..].copy_from_slice(self.s.as_bytes());
+        bytes
+    }
+
+    /// Deserialize a proof from the encoding produced by
+    /// [`DlogProof::to_bytes`].
+    ///
+    /// Returns `None` if the response is not a canonical scalar encoding.
+    /// The commitment is only checked when the proof is verified.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Option<DlogProof> {
+        let mut R = [0u8; 32];
+        let mut s = [0u8; 32];
+        R.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..]);
+        let s = Option::from(Scalar::from_canonical_bytes(s))?;
+        Some(DlogProof {
+            R: CompressedRistretto(R),
+            s,
+        })
+    }
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "rand_core"))]
+mod test {
+    use super::*;
+    use rand_core::OsRng;
+    use sha2::Sha512;
+
+    fn keypair() -> (Scalar, RistrettoPoint) {
+        let x = Scalar::random(&mut OsRng);
+        (x, RistrettoPoint::mul_base(&x))
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        let (x, X) = keypair();
+        let (_, Y) = keypair();
+        let proof = DlogProof::prove::<Sha512, _>(&mut OsRng, b"test", &x, &X);
+
+        assert!(proof.verify::<Sha512>(b"test", &X));
+        assert!(!proof.verify::<Sha512>(b"other", &X));
+        assert!(!proof.verify::<Sha512>(b"test", &Y));
+
+        let tampered = DlogProof {
+            s: proof.s + Scalar::ONE,
+            ..proof
+        };
+        assert!(!tampered.verify::<Sha512>(b"test", &X));
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let (x, X) = keypair();
+        let proof = DlogProof::prove::<Sha512, _>(&mut OsRng, b"test", &x, &X);
+        assert_eq!(DlogProof::from_bytes(&proof.to_bytes()), Some(proof));
+
+        let mut bytes = proof.to_bytes();
+        bytes[63] = 0xff;
+        assert_eq!(DlogProof::from_bytes(&bytes), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn batch_verify() {
+        let keys: Vec<(Scalar, RistrettoPoint)> = (0..8).map(|_| keypair()).collect();
+        let Xs: Vec<RistrettoPoint> = keys.iter().map(|(_, X)| *X).collect();
+        let mut proofs: Vec<DlogProof> = keys
+            .iter()
+            .map(|(x, X)| DlogProof::prove::<Sha512, _>(&mut OsRng, b"batch", x, X))
+            .collect();
+
+        assert!(DlogProof::batch_verify::<Sha512, _>(
+            &mut OsRng, b"batch", &proofs, &Xs
+        ));
+        assert!(DlogProof::batch_verify::<Sha512, _>(
+            &mut OsRng,
+            b"batch",
+            &[],
+            &[]
+        ));
+        assert!(!DlogProof::batch_verify::<Sha512, _>(
+            &mut OsRng, b"other", &proofs, &Xs
+        ));
+
+        proofs[3].s += Scalar::ONE;
+        assert!(!DlogProof::batch_verify::<Sha512, _>(
+            &mut OsRng, b"batch", &proofs, &Xs
+        ));
+
+        // An invalid commitment encoding is rejected rather than panicking.
+        proofs[3].R = CompressedRistretto([0xff; 32]);
+        assert!(!DlogProof::batch_verify::<Sha512, _>(
+            &mut OsRng, b"batch", &proofs, &Xs
+        ));
+    }
+}
@@ -16,6 +16,7 @@
 use core::borrow::Borrow;
 
 use crate::scalar::{clamp_integer, Scalar};
+use subtle::Choice;
 use subtle::ConstantTimeEq;
 
 // ------------------------------------------------------------------------
@@ -47,7 +48,60 @@ where
     }
 }
 
+/// Trait for testing if a curve point is equivalent to the identity point,
+/// in constant time.
+pub trait CtIsIdentity {
+    /// Return `Choice(1)` if this element is the identity element of the
+    /// curve, and `Choice(0)` otherwise.
+    fn ct_is_identity(&self) -> Choice;
+}
+
+impl<T> CtIsIdentity for T
+where
+    T: ConstantTimeEq + Identity,
+{
+    fn ct_is_identity(&self) -> Choice {
+        self.ct_eq(&T::identity())
+    }
+}
+
 /// A precomputed table of basepoints, for optimising scalar multiplications.
+///
+/// This is implemented by the Edwards and Ristretto tables of every radix,
+/// so code that only needs fixed-base multiplication can be generic over
+/// the table:
+///
+#[cfg_attr(feature = "precomputed-tables", doc = "```")]
+#[cfg_attr(not(feature = "precomputed-tables"), doc = "```ignore")]
+/// use curve25519_dalek::constants::RISTRETTO_BASEPOINT_POINT;
+/// use curve25519_dalek::ristretto::{RistrettoBasepointTable, RistrettoBasepointTableRadix256};
+/// use curve25519_dalek::scalar::Scalar;
+/// use curve25519_dalek::traits::BasepointTable;
+///
+/// fn commit<T: BasepointTable>(g: &T, h: &T, v: &Scalar, r: &Scalar) -> T::Point
+/// where
+///     T::Point: core::ops::Add<Output = T::Point>,
+/// {
+///     g.mul_base(v) + h.mul_base(r)
+/// }
+///
+/// let H = RISTRETTO_BASEPOINT_POINT * Scalar::from(1234u64);
+/// let (v, r) = (Scalar::from(5u64), Scalar::from(87329482u64));
+///
+/// let small = commit(
+///     &RistrettoBasepointTable::create(&RISTRETTO_BASEPOINT_POINT),
+///     &RistrettoBasepointTable::create(&H),
+///     &v,
+///     &r,
+/// );
+/// let large = commit(
+///     &RistrettoBasepointTableRadix256::create(&RISTRETTO_BASEPOINT_POINT),
+///     &RistrettoBasepointTableRadix256::create(&H),
+///     &v,
+///     &r,
+/// );
+/// assert_eq!(small, large);
+/// ```
 pub trait BasepointTable {
     /// The type of point contained within this table.
     type Point;
@@ -133,6 +187,30 @@ pub trait MultiscalarMul {
         J::Item: Borrow<Self::Point>;
 }
 
+/// The algorithm used for a variable-time multiscalar multiplication.
+///
+/// [`VartimeMultiscalarMul`] picks one by the number of points, which is
+/// the `Auto` behaviour.  The crossover depends on the backend and on how
+/// the inputs are used, so callers with a known workload can choose
+/// explicitly, e.g. with `EdwardsPoint::vartime_multiscalar_mul_with_algorithm`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum MultiscalarMulAlgorithm {
+    /// Choose by input size, as [`VartimeMultiscalarMul`] does.
+    Auto,
+    /// Interleaved window multiplication (Straus's method), best for
+    /// small inputs.
+    Straus,
+    /// Bucket accumulation (Pippenger's method), best for large inputs.
+    Pippenger,
+}
+
+impl Default for MultiscalarMulAlgorithm {
+    fn default() -> Self {
+        MultiscalarMulAlgorithm::Auto
+    }
+}
+
 /// A trait for variable-time multiscalar multiplication without precomputation.
 pub trait VartimeMultiscalarMul {
     /// The type of point being multiplied, e.g., `RistrettoPoint`.
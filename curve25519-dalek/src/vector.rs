@@ -0,0 +1,43 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+//! Vectorized field arithmetic, for writing 4-way parallel algorithms.
+//!
+//! Each type here holds four field elements in lanes \\((A, B, C, D)\\)
+//! and operates on all four at once.  These are the types used by the
+//! crate's own SIMD backends: see the [parallel formulas] notes for the
+//! strategy they implement.
+//!
+//! * `avx2::FieldElement2625x4` stores each element in radix
+//!   \\(2\^{25.5}\\), and its operations document the bounds they require
+//!   of their inputs and guarantee for their outputs;
+//! * `ifma::F51x4Unreduced` and `ifma::F51x4Reduced` store each
+//!   element in radix \\(2\^{51}\\), and the type records whether the
+//!   coefficients have been reduced.
+//!
+//! These APIs are designed for speed and not safety.  Violating a bound
+//! precondition produces wrong results.
+//!
+//! # Availability
+//!
+//! The crate selects its own vector backend by runtime CPU feature
+//! detection, but these types use their instructions unconditionally.
+//! Each module is therefore only available when the crate is compiled
+//! with the corresponding target features enabled, for instance with
+//! `RUSTFLAGS="-C target-feature=+avx2"` for `avx2`, or
+//! `RUSTFLAGS="-C target-feature=+avx512ifma,+avx512vl"` on Rust 1.89 or
+//! later for `ifma`.
+//!
+//! [parallel formulas]: https://docs.rs/curve25519-dalek/latest/curve25519_dalek/backend/vector/index.html
+
+#[cfg(target_feature = "avx2")]
+pub use crate::backend::vector::avx2::field as avx2;
+
+#[cfg(all(
+    curve25519_dalek_avx512,
+    target_feature = "avx512ifma",
+    target_feature = "avx512vl"
+))]
+pub use crate::backend::vector::ifma::field as ifma;
@@ -0,0 +1,169 @@
+// -*- mode: rust; -*-
+//
+// This file is part of curve25519-dalek.
+// See LICENSE for licensing information.
+
+#![allow(non_snake_case)]
+
+//! Feldman verifiable secret sharing over the Ristretto group.
+//!
+//! A dealer shares a secret \\(a\_0\\) by choosing a random polynomial
+//! $$
+//! f(x) = a\_0 + a\_1 x + \cdots + a\_{t-1} x\^{t-1}
+//! $$
+//! over the scalar field and handing participant \\(i\\) the share
+//! \\(f(i)\\).  Any \\(t\\) shares recover the secret, while fewer reveal
+//! nothing about it.
+//!
+//! To make the shares verifiable, the dealer also publishes the
+//! commitment vector \\(C\_j = a\_j B\\), where \\(B\\) is the Ristretto
+//! basepoint.  A participant holding the share \\(s\_i\\) checks it with
+//! $$
+//! s\_i B = \sum\_{j} i\^j C\_j.
+//! $$
+//!
+//! These are the building blocks of a distributed key generation protocol;
+//! this module deliberately leaves transport, complaints and participant
+//! bookkeeping to the caller.
+//!
+//! # Example
+//!
+#![cfg_attr(all(feature = "alloc", feature = "rand_core"), doc = "```")]
+#![cfg_attr(not(all(feature = "alloc", feature = "rand_core")), doc = "```ignore")]
+//! # fn main() {
+//! use curve25519_dalek::scalar::Scalar;
+//! use curve25519_dalek::vss;
+//! use rand_core::OsRng;
+//!
+//! // A 2-of-n sharing of a random secret.
+//! let coefficients = [Scalar::random(&mut OsRng), Scalar::random(&mut OsRng)];
+//! let commitment = vss::commit(&coefficients);
+//!
+//! let index = Scalar::from(3u64);
+//! let share = vss::evaluate(&coefficients, &index);
+//! assert!(vss::verify_share(&commitment, &index, &share));
+//! # }
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use core::iter;
+
+#[cfg(feature = "alloc")]
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "alloc")]
+use crate::ristretto::RistrettoPoint;
+use crate::scalar::Scalar;
+#[cfg(feature = "alloc")]
+use crate::traits::{IsIdentity, VartimeMultiscalarMul};
+
+/// Commit to the polynomial with the given `coefficients`, lowest degree
+/// first.
+///
+/// Returns the commitment vector \\(C\_j = a\_j B\\).  The first entry is
+/// the public key corresponding to the shared secret \\(a\_0\\).
+///
+/// This function is constant time in the coefficients.
+#[cfg(feature = "alloc")]
+pub fn commit(coefficients: &[Scalar]) -> Vec<RistrettoPoint> {
+    coefficients.iter().map(RistrettoPoint::mul_base).collect()
+}
+
+/// Evaluate the polynomial with the given `coefficients`, lowest degree
+/// first, at `index`.
+///
+/// Used by the dealer to compute the share \\(f(i)\\) of participant
+/// \\(i\\).  Participant indices must be nonzero, since the evaluation at
+/// zero is the secret itself.
+///
+/// This function is constant time in the coefficients and the index; its
+/// running time depends only on the number of coefficients.
+pub fn evaluate(coefficients: &[Scalar], index: &Scalar) -> Scalar {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Scalar::ZERO, |acc, a_j| acc * index + a_j)
+}
+
+/// Verify the `share` held by the participant at `index` against the
+/// dealer's `commitment` vector.
+///
+/// Returns `true` if and only if \\(s\_i B = \sum\_{j} i\^j C\_j\\).  An
+/// empty commitment vector, a commitment to a zero secret (whose first
+/// entry is the identity), or a zero `index` (whose share would be the
+/// secret itself) is always rejected.
+///
+/// The right-hand side depends only on public data and is computed in
+/// variable time; the share itself is only used in constant-time
+/// operations.
+#[cfg(feature = "alloc")]
+pub fn verify_share(commitment: &[RistrettoPoint], index: &Scalar, share: &Scalar) -> bool {
+    if index == &Scalar::ZERO {
+        return false;
+    }
+    match commitment.first() {
+        None => return false,
+        Some(C_0) if C_0.is_identity() => return false,
+        Some(_) => {}
+    }
+
+    // The multiscalar multiplication needs exact size hints, which
+    // `successors` cannot provide.
+    let powers: Vec<Scalar> = iter::successors(Some(Scalar::ONE), |power| Some(power * index))
+        .take(commitment.len())
+        .collect();
+    let expected = RistrettoPoint::vartime_multiscalar_mul(&powers, commitment);
+
+    RistrettoPoint::mul_base(share).ct_eq(&expected).into()
+}
+
+// ------------------------------------------------------------------------
+// Tests
+// ------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "alloc", feature = "rand_core"))]
+mod test {
+    use super::*;
+    use rand_core::OsRng;
+
+    #[test]
+    fn shares_verify_against_commitment() {
+        let coefficients: Vec<Scalar> = (0..4).map(|_| Scalar::random(&mut OsRng)).collect();
+        let commitment = commit(&coefficients);
+        assert_eq!(commitment[0], RistrettoPoint::mul_base(&coefficients[0]));
+
+        for i in 1..8u64 {
+            let index = Scalar::from(i);
+            let share = evaluate(&coefficients, &index);
+            assert!(verify_share(&commitment, &index, &share));
+
+            // A share for another participant, or a tampered share, fails.
+            assert!(!verify_share(&commitment, &Scalar::from(i + 1), &share));
+            assert!(!verify_share(&commitment, &index, &(share + Scalar::ONE)));
+        }
+
+        // Evaluating at zero recovers the secret.
+        assert_eq!(evaluate(&coefficients, &Scalar::ZERO), coefficients[0]);
+    }
+
+    #[test]
+    fn degenerate_commitments_are_rejected() {
+        assert!(!verify_share(&[], &Scalar::ONE, &Scalar::ZERO));
+
+        let coefficients = [Scalar::ZERO, Scalar::random(&mut OsRng)];
+        let commitment = commit(&coefficients);
+        let share = evaluate(&coefficients, &Scalar::ONE);
+        assert!(!verify_share(&commitment, &Scalar::ONE, &share));
+    }
+
+    #[test]
+    fn zero_index_is_rejected() {
+        let coefficients: Vec<Scalar> = (0..3).map(|_| Scalar::random(&mut OsRng)).collect();
+        let commitment = commit(&coefficients);
+        let secret = evaluate(&coefficients, &Scalar::ZERO);
+        assert!(!verify_share(&commitment, &Scalar::ZERO, &secret));
+    }
+}
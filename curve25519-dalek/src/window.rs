@@ -31,6 +31,24 @@ use crate::edwards::EdwardsPoint;
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
+/// Load a table entry for a constant-time `select`.
+///
+/// With the `paranoid-lookups` feature, the load is a volatile read, so the
+/// compiler can neither skip entries nor narrow the scan to the one being
+/// selected; every entry of the table is read in full, in order, whatever
+/// the secret index.  Otherwise this is a plain copy.
+#[inline(always)]
+fn read_entry<T: Copy>(entry: &T) -> T {
+    cfg_if! {
+        if #[cfg(feature = "paranoid-lookups")] {
+            // SAFETY: `entry` is a valid, aligned reference to a `Copy` value.
+            unsafe { core::ptr::read_volatile(entry) }
+        } else {
+            *entry
+        }
+    }
+}
+
 macro_rules! impl_lookup_table {
     (Name = $name:ident, Size = $size:expr, SizeNeg = $neg:expr, SizeRange = $range:expr, ConversionRange = $conv_range:expr) => {
         /// A lookup table of precomputed multiples of a point \\(P\\), used to
@@ -64,7 +82,7 @@ macro_rules! impl_lookup_table {
                 for j in $range {
                     // Copy `points[j-1] == j*P` onto `t` in constant time if `|x| == j`.
                     let c = (xabs as u16).ct_eq(&(j as u16));
-                    t.conditional_assign(&self.0[j - 1], c);
+                    t.conditional_assign(&read_entry(&self.0[j - 1]), c);
                 }
                 // Now t == |x| * P.
 